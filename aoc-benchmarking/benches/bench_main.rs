@@ -1,4 +1,5 @@
 use criterion::criterion_main;
+use rayon::prelude::*;
 
 use a_long_walk::ALongWalk;
 use aoc_benchmarking::aoc_benches;
@@ -29,7 +30,8 @@ use you_give_a_seed_a_fertilizer::YouGiveASeedAFertilizer;
 // import_marker
 
 criterion_main! {
-    benches
+    benches,
+    extra_benches
 }
 
 aoc_benches! {
@@ -197,3 +199,33 @@ aoc_benches! {
     ),
     // bench_marker
 }
+
+/// Compare propagating every starting beam from scratch against going through
+/// the `PropagateCache`, which visits fewer tiles.
+fn day_016_propagate_cache(c: &mut Criterion) {
+    if !aoc_benchmarking::day_selected(TheFloorWillBeLava::DAY) {
+        return;
+    }
+
+    let mut group = c.benchmark_group(format!(
+        "{} propagate cache",
+        TheFloorWillBeLava::problem_label()
+    ));
+    let input = std::fs::read_to_string("../day-016-the-floor-will-be-lava/input.txt")
+        .expect("Could not load input");
+    let problem = TheFloorWillBeLava::instance(&input).expect("Could not parse input");
+
+    group.bench_function("Uncached", |b| {
+        b.iter(|| {
+            problem
+                .starting_particles()
+                .into_par_iter()
+                .map(|p| problem.propagate(p).count())
+                .max()
+        })
+    });
+    group.bench_function("Cached", |b| b.iter(|| problem.propagate_all()));
+    group.finish();
+}
+
+criterion_group!(extra_benches, day_016_propagate_cache);
@@ -0,0 +1,92 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use aoc_plumbing::Problem;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// A wrapper around the system allocator that keeps track of how many bytes
+/// have been allocated. This is installed as the global allocator when the
+/// `alloc-count` feature is enabled.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+/// The allocations made while running some piece of code.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The total number of bytes allocated.
+    pub allocated: usize,
+    /// The most bytes that were live at once, relative to the start.
+    pub peak: usize,
+}
+
+/// Run `f`, recording the allocations it makes.
+///
+/// The counters are global, so allocations made by other threads at the same
+/// time are included as well.
+pub fn measure<F, R>(f: F) -> (R, AllocationStats)
+where
+    F: FnOnce() -> R,
+{
+    let start_allocated = ALLOCATED.load(Ordering::Relaxed);
+    let start_current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start_current, Ordering::Relaxed);
+
+    let res = f();
+
+    let stats = AllocationStats {
+        allocated: ALLOCATED.load(Ordering::Relaxed) - start_allocated,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(start_current),
+    };
+
+    (res, stats)
+}
+
+/// Solve the given problem once, printing the number of bytes allocated while
+/// doing so.
+pub fn report_allocations<P: Problem>(input: &str) -> Result<AllocationStats, P::ProblemError> {
+    let (res, stats) = measure(|| P::solve(input));
+    res?;
+
+    println!(
+        "{}: {} bytes allocated, {} bytes peak",
+        P::problem_label(),
+        stats.allocated,
+        stats.peak
+    );
+
+    Ok(stats)
+}
@@ -3,9 +3,17 @@ macro_rules! aoc_bench {
     // "standard" solution with two distinct parts
     ($name:ident, $input:literal, $problem:ty, $part1_desc:literal, $part2_desc:literal) => {
         pub fn $name(c: &mut Criterion) {
+            if !aoc_benchmarking::day_selected(<$problem>::DAY) {
+                return;
+            }
+
             let mut group = c.benchmark_group(<$problem>::problem_label());
             let input = std::fs::read_to_string($input).expect("Could not load input");
+            group.throughput(criterion::Throughput::Bytes(input.len() as u64));
 
+            group.bench_function("Parse only", |b| {
+                b.iter(|| <$problem>::instance(&input).expect("Could not parse input"))
+            });
             group.bench_function($part1_desc, |b| {
                 let mut problem = <$problem>::instance(&input).expect("Could not parse input");
                 b.iter(|| problem.part_one().expect("Failed to solve part one"))
@@ -23,9 +31,17 @@ macro_rules! aoc_bench {
     // combined solution
     ($name:ident, $input:literal, $problem:ty, $combined_desc:literal) => {
         pub fn $name(c: &mut Criterion) {
+            if !aoc_benchmarking::day_selected(<$problem>::DAY) {
+                return;
+            }
+
             let mut group = c.benchmark_group(<$problem>::problem_label());
             let input = std::fs::read_to_string($input).expect("Could not load input");
+            group.throughput(criterion::Throughput::Bytes(input.len() as u64));
 
+            group.bench_function("Parse only", |b| {
+                b.iter(|| <$problem>::instance(&input).expect("Could not parse input"))
+            });
             group.bench_function($combined_desc, |b| {
                 b.iter(|| <$problem>::solve(&input).expect("Failed to solve"))
             });
@@ -52,8 +68,10 @@ macro_rules! aoc_benches {
             group.bench_function("Total runtime for all solutions, including parsing", |b| {
                 b.iter(|| {
                     $(
-                        let input = std::fs::read_to_string($input).expect("Failed to open file");
-                        <$problem>::solve(&input).expect("Failed to solve");
+                        if aoc_benchmarking::day_selected(<$problem>::DAY) {
+                            let input = std::fs::read_to_string($input).expect("Failed to open file");
+                            <$problem>::solve(&input).expect("Failed to solve");
+                        }
                     )*
                 })
             });
@@ -1 +1,60 @@
+use std::{num::ParseIntError, sync::OnceLock};
+
+#[cfg(feature = "alloc-count")]
+pub mod alloc;
 pub mod helper_macros;
+
+#[cfg(feature = "alloc-count")]
+#[global_allocator]
+static GLOBAL: alloc::CountingAllocator = alloc::CountingAllocator;
+
+/// The env var used to restrict the benchmarks to a comma-separated list of
+/// days, e.g. `AOC_BENCH_DAY=1,16`.
+pub const DAY_FILTER_VAR: &str = "AOC_BENCH_DAY";
+
+/// Parse a comma-separated list of days. Empty entries are skipped, but
+/// anything else that isn't a day is an error.
+pub fn parse_day_filter(raw: &str) -> Result<Vec<usize>, ParseIntError> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(str::parse::<usize>)
+        .collect()
+}
+
+/// Whether the given day should be benchmarked according to `AOC_BENCH_DAY`.
+///
+/// Every day is selected if the env var is unset or empty.
+///
+/// # Panics
+///
+/// Panics if `AOC_BENCH_DAY` contains something that isn't a day, rather than
+/// silently benchmarking every day.
+pub fn day_selected(day: usize) -> bool {
+    static FILTER: OnceLock<Vec<usize>> = OnceLock::new();
+
+    let filter = FILTER.get_or_init(|| {
+        std::env::var(DAY_FILTER_VAR)
+            .map(|raw| {
+                parse_day_filter(&raw)
+                    .unwrap_or_else(|e| panic!("Invalid {} value {:?}: {}", DAY_FILTER_VAR, raw, e))
+            })
+            .unwrap_or_default()
+    });
+
+    filter.is_empty() || filter.contains(&day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_filter() {
+        assert_eq!(parse_day_filter("1, 16,25").unwrap(), vec![1, 16, 25]);
+        assert_eq!(parse_day_filter("1,").unwrap(), vec![1]);
+        assert!(parse_day_filter("").unwrap().is_empty());
+        assert!(parse_day_filter("l6").is_err());
+        assert!(parse_day_filter("1,x,25").is_err());
+    }
+}
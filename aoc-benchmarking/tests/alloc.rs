@@ -0,0 +1,23 @@
+#![cfg(feature = "alloc-count")]
+
+use aoc_benchmarking::alloc::{measure, report_allocations};
+use trebuchet::Trebuchet;
+
+const INPUT: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+#[test]
+fn counts_allocations() {
+    let stats = report_allocations::<Trebuchet>(INPUT).unwrap();
+    assert!(stats.allocated > 0);
+    assert!(stats.peak > 0);
+
+    let (v, stats) = measure(|| vec![0_u8; 1024]);
+    assert_eq!(v.len(), 1024);
+    assert!(stats.allocated >= 1024);
+}
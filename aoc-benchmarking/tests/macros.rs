@@ -0,0 +1,86 @@
+use std::{path::Path, str::FromStr, time::Duration};
+
+use aoc_plumbing::Problem;
+use criterion::Criterion;
+
+struct Example;
+
+impl FromStr for Example {
+    type Err = anyhow::Error;
+
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(Self)
+    }
+}
+
+impl Problem for Example {
+    const DAY: usize = 1;
+    const TITLE: &'static str = "example";
+    const README: &'static str = "";
+
+    type ProblemError = anyhow::Error;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn part_one(&mut self) -> Result<Self::P1, Self::ProblemError> {
+        Ok(1)
+    }
+
+    fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
+        Ok(2)
+    }
+}
+
+aoc_benchmarking::aoc_bench!(two_parts, "Cargo.toml", Example, "Part 1", "Part 2");
+aoc_benchmarking::aoc_bench!(combined, "Cargo.toml", Example, "Combined");
+
+// both forms just need to produce functions criterion_group! will accept
+#[test]
+fn bench_macro_expands() {
+    let _: fn(&mut Criterion) = two_parts;
+    let _: fn(&mut Criterion) = combined;
+}
+
+// criterion lays out its output as <group>/<function>, so the directories it
+// writes tell us which ids the generated benches used
+#[test]
+fn group_names_include_day() {
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("bench-group-names");
+    let _ = std::fs::remove_dir_all(&out);
+
+    let mut c = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(1))
+        .measurement_time(Duration::from_millis(1))
+        .nresamples(1000)
+        .without_plots()
+        .output_directory(&out);
+
+    two_parts(&mut c);
+    combined(&mut c);
+
+    for id in [
+        "Parse only",
+        "Part 1",
+        "Part 2",
+        "Combined (including parsing)",
+        "Combined",
+    ] {
+        assert!(out.join("001 example").join(id).is_dir(), "missing {}", id);
+    }
+}
+
+// run every variant (including parse only) against the dummy problem, with
+// the measurement cut down as far as criterion allows
+#[test]
+fn bench_macro_runs() {
+    let mut c = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(1))
+        .measurement_time(Duration::from_millis(1))
+        .nresamples(1000)
+        .without_plots();
+
+    two_parts(&mut c);
+    combined(&mut c);
+}
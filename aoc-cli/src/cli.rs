@@ -1,11 +1,14 @@
 use std::{
+    fmt::Display,
+    io::Read,
     marker::PhantomData,
     path::{Path, PathBuf},
+    time::Instant,
 };
 
 use a_long_walk::ALongWalk;
-use anyhow::{Context, Result};
-use aoc_plumbing::Problem;
+use anyhow::{bail, Context, Result};
+use aoc_plumbing::{Problem, Solution, TimingBreakdown};
 use aplenty::Aplenty;
 use camel_cards::CamelCards;
 use clap::{Args, CommandFactory, Parser, Subcommand};
@@ -13,6 +16,7 @@ use clap_complete::{generate, shells::Zsh};
 use clumsy_crucible::ClumsyCrucible;
 use cosmic_expansion::CosmicExpansion;
 use cube_conundrum::CubeConundrum;
+use flate2::read::GzDecoder;
 use gear_ratios::GearRatios;
 use haunted_wasteland::HauntedWasteland;
 use hot_springs::HotSprings;
@@ -26,11 +30,13 @@ use point_of_incidence::PointOfIncidence;
 use pulse_propagation::PulsePropagation;
 use sand_slabs::SandSlabs;
 use scratchcards::Scratchcards;
+use serde::{Deserialize, Serialize};
 use snowverload::Snowverload;
 use step_counter::StepCounter;
 use the_floor_will_be_lava::TheFloorWillBeLava;
 use trebuchet::Trebuchet;
 use wait_for_it::WaitForIt;
+use xxhash_rust::xxh3::xxh3_64;
 use you_give_a_seed_a_fertilizer::YouGiveASeedAFertilizer;
 // import_marker
 
@@ -66,6 +72,18 @@ macro_rules! generate_cli {
             Run(Run),
 
             #[command(display_order = 31)]
+            Verify(Verify),
+
+            #[command(display_order = 32)]
+            RunAll(RunAll),
+
+            #[command(display_order = 33)]
+            TimeAll(TimeAll),
+
+            #[command(display_order = 34)]
+            List(List),
+
+            #[command(display_order = 40)]
             GenerateCompletions(GenerateCompletions),
         }
 
@@ -74,6 +92,10 @@ macro_rules! generate_cli {
                 match self {
                     Self::GenerateCompletions(cmd) => cmd.run(),
                     Self::Run(cmd) => cmd.run(),
+                    Self::Verify(cmd) => cmd.run(),
+                    Self::RunAll(cmd) => cmd.run(),
+                    Self::TimeAll(cmd) => cmd.run(),
+                    Self::List(cmd) => cmd.run(),
                     $(
                     Self::$name(cmd) => cmd.run(),
                     )*
@@ -94,7 +116,8 @@ macro_rules! generate_cli {
             #[clap(env = "AOC_DAY")]
             day: usize,
 
-            /// The path to the input for this solution.
+            /// The path to the input for this solution, or `-` to read the
+            /// input from stdin. Gzipped inputs are decompressed.
             ///
             /// This may be specified instead by setting the `AOC_INPUT` env
             /// var. An explicitly passed value will take precendence over the
@@ -109,13 +132,47 @@ macro_rules! generate_cli {
             /// will take precendence over the env var.
             #[clap(short, long, env = "AOC_JSON")]
             json: bool,
+
+            /// Only solve the specified part (1 or 2).
+            #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+            part: Option<u8>,
+
+            /// Also report how long parsing and each part took.
+            #[clap(short, long, conflicts_with = "part")]
+            timing: bool,
+
+            /// Cache the solution in the specified directory.
+            ///
+            /// The cached solution is keyed by a hash of the input, and is
+            /// reused instead of solving again when the input is unchanged.
+            #[clap(long, conflicts_with_all = ["part", "timing"])]
+            cache: Option<PathBuf>,
+
+            /// Solve the input this many times and report the min, median,
+            /// and max time taken to solve.
+            #[clap(
+                short,
+                long,
+                value_parser = clap::value_parser!(u32).range(1..),
+                conflicts_with_all = ["part", "timing", "cache"],
+            )]
+            repeat: Option<u32>,
         }
 
         impl Run {
             pub fn run(&self) -> Result<()> {
                 match self.day {
                     $(
-                    $day => _run::<$name>(&self.input, self.json),
+                    $day => match self.repeat {
+                        Some(runs) => _repeat::<$name>(&self.input, self.json, runs),
+                        None => _run::<$name>(
+                            &self.input,
+                            self.json,
+                            self.part,
+                            self.timing,
+                            self.cache.as_deref(),
+                        ),
+                    },
                     )*
                     _ => {
                         if self.json {
@@ -128,6 +185,239 @@ macro_rules! generate_cli {
                 }
             }
         }
+
+        /// Solve a specified day and compare the answers to the expected ones.
+        ///
+        /// The expected answers may be specified individually, or loaded from
+        /// a json file in the format produced by `run --json`. This exits with
+        /// a nonzero status if any answer does not match.
+        #[derive(Args)]
+        pub(crate) struct Verify {
+            /// The day to verify.
+            ///
+            /// This may be specified instead by setting the `AOC_DAY` env var.
+            /// An explicitly passed value will take precendence over the env
+            /// var.
+            #[clap(env = "AOC_DAY")]
+            day: usize,
+
+            /// The path to the input for this solution, or `-` to read the
+            /// input from stdin. Gzipped inputs are decompressed.
+            ///
+            /// This may be specified instead by setting the `AOC_INPUT` env
+            /// var. An explicitly passed value will take precendence over the
+            /// env var.
+            #[clap(env = "AOC_INPUT")]
+            input: PathBuf,
+
+            /// The expected answer for part one.
+            #[clap(long = "expect-1")]
+            expect_one: Option<String>,
+
+            /// The expected answer for part two.
+            #[clap(long = "expect-2")]
+            expect_two: Option<String>,
+
+            /// A json file containing the expected answers.
+            ///
+            /// Answers specified with `--expect-1` or `--expect-2` take
+            /// precedence over the answers in this file.
+            #[clap(short, long)]
+            answers: Option<PathBuf>,
+        }
+
+        impl Verify {
+            pub fn run(&self) -> Result<()> {
+                let expected = self.expected()?;
+
+                match self.day {
+                    $(
+                    $day => _verify::<$name>(&self.input, &expected),
+                    )*
+                    _ => bail!("Day {} is not implemented", self.day),
+                }
+            }
+
+            fn expected(&self) -> Result<[Option<String>; 2]> {
+                let mut expected = [None, None];
+
+                if let Some(ref path) = self.answers {
+                    let raw = std::fs::read_to_string(path)
+                        .context("Could not read answers file")?;
+                    let answers: serde_json::Value =
+                        serde_json::from_str(&raw).context("Could not parse answers file")?;
+
+                    for (slot, key) in expected.iter_mut().zip(["part_one", "part_two"]) {
+                        *slot = answers.get(key).map(display_value);
+                    }
+                }
+
+                if let Some(ref answer) = self.expect_one {
+                    expected[0] = Some(answer.clone());
+                }
+
+                if let Some(ref answer) = self.expect_two {
+                    expected[1] = Some(answer.clone());
+                }
+
+                if expected.iter().all(Option::is_none) {
+                    bail!("No expected answers were specified");
+                }
+
+                Ok(expected)
+            }
+        }
+
+        /// Run the solutions for every implemented day.
+        ///
+        /// The inputs are located by looking for `day-XXX-*/input.txt` (or
+        /// `input.txt.gz`) in the specified directory, where `XXX` is the
+        /// zero-padded day. Days without an input are skipped.
+        #[derive(Args)]
+        pub(crate) struct RunAll {
+            /// The directory containing the per-day input directories.
+            #[clap(default_value = ".")]
+            inputs: PathBuf,
+
+            /// Display the output as json.
+            ///
+            /// This may be specified instead by setting the `AOC_JSON` env
+            /// var to `true`. If the flag is passed, on the command line, it
+            /// will take precendence over the env var.
+            #[clap(short, long, env = "AOC_JSON")]
+            json: bool,
+        }
+
+        impl RunAll {
+            pub fn run(&self) -> Result<()> {
+                let mut solutions = Vec::new();
+
+                $(
+                if let Some(input) = find_input(&self.inputs, $day)? {
+                    solutions.push(DaySolution::new::<$name>(&_solve::<$name>(&input)?)?);
+                }
+                )*
+
+                if self.json {
+                    println!("{}", serde_json::to_string(&solutions)?);
+                } else {
+                    let rows = solutions.iter().map(|s| s.row()).collect::<Vec<_>>();
+                    print_table(["day", "title", "part 1", "part 2"], &rows);
+                }
+
+                Ok(())
+            }
+        }
+
+        /// Time the solutions for every implemented day.
+        ///
+        /// Each day is solved once, and the time taken for parsing and each
+        /// part is reported, along with the total time across all days. The
+        /// inputs are located the same way as for `run-all`, and the results
+        /// are sorted from slowest to fastest.
+        #[derive(Args)]
+        pub(crate) struct TimeAll {
+            /// The directory containing the per-day input directories.
+            #[clap(default_value = ".")]
+            inputs: PathBuf,
+
+            /// Display the output as json.
+            ///
+            /// This may be specified instead by setting the `AOC_JSON` env
+            /// var to `true`. If the flag is passed, on the command line, it
+            /// will take precendence over the env var.
+            #[clap(short, long, env = "AOC_JSON")]
+            json: bool,
+        }
+
+        impl TimeAll {
+            pub fn run(&self) -> Result<()> {
+                let mut timings = Vec::new();
+
+                $(
+                if let Some(input) = find_input(&self.inputs, $day)? {
+                    timings.push(DayTiming::new::<$name>(&_time::<$name>(&input)?));
+                }
+                )*
+
+                timings.sort_by(|a, b| b.total_ns.cmp(&a.total_ns));
+                let total_ns: u128 = timings.iter().map(|t| t.total_ns).sum();
+
+                if self.json {
+                    println!(
+                        "{}",
+                        serde_json::json!({ "days": timings, "total_ns": total_ns })
+                    );
+                } else {
+                    let mut rows = timings.iter().map(|t| t.row()).collect::<Vec<_>>();
+                    rows.push([
+                        String::from("Total"),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                        String::new(),
+                        format_ms(total_ns),
+                    ]);
+                    print_table(
+                        [
+                            "day",
+                            "title",
+                            "parse (ms)",
+                            "part 1 (ms)",
+                            "part 2 (ms)",
+                            "total (ms)",
+                        ],
+                        &rows,
+                    );
+                }
+
+                Ok(())
+            }
+        }
+
+        /// List the implemented days.
+        #[derive(Args)]
+        pub(crate) struct List {
+            /// Display the output as json.
+            ///
+            /// This may be specified instead by setting the `AOC_JSON` env
+            /// var to `true`. If the flag is passed, on the command line, it
+            /// will take precendence over the env var.
+            #[clap(short, long, env = "AOC_JSON")]
+            json: bool,
+        }
+
+        impl List {
+            pub fn run(&self) -> Result<()> {
+                if self.json {
+                    let days = [$($name::meta(),)*];
+                    println!("{}", serde_json::to_string(&days)?);
+                } else {
+                    $(
+                    println!("{}", $name::problem_label());
+                    )*
+                }
+
+                Ok(())
+            }
+        }
+
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+
+            #[test]
+            fn registered_examples() {
+                $(
+                if let Some(result) = $name::check_example() {
+                    let matched = result
+                        .map_err(Into::<anyhow::Error>::into)
+                        .expect("Failed to solve example");
+                    assert!(matched, "The example for day {} did not match", $day);
+                }
+                )*
+            }
+        }
     };
 }
 
@@ -136,13 +426,22 @@ pub(crate) struct Solver<T>
 where
     T: Problem,
 {
-    /// The path to the input for this solution.
+    /// The path to the input for this solution, or `-` to read the input
+    /// from stdin. Gzipped inputs are decompressed.
     input: PathBuf,
 
     /// Display the output as json.
     #[clap(short, long)]
     json: bool,
 
+    /// Only solve the specified part (1 or 2).
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Also report how long parsing and each part took.
+    #[clap(short, long, conflicts_with = "part")]
+    timing: bool,
+
     #[clap(skip)]
     _phantom: PhantomData<T>,
 }
@@ -153,30 +452,456 @@ where
     <T as Problem>::ProblemError: Into<anyhow::Error>,
 {
     pub fn run(&self) -> Result<()> {
-        _run::<T>(&self.input, self.json)
+        _run::<T>(&self.input, self.json, self.part, self.timing, None)
     }
 }
 
-fn _run<T>(input_file: &Path, json: bool) -> Result<()>
+fn _run<T>(
+    input_file: &Path,
+    json: bool,
+    part: Option<u8>,
+    timing: bool,
+    cache: Option<&Path>,
+) -> Result<()>
 where
     T: Problem,
     <T as Problem>::ProblemError: Into<anyhow::Error>,
 {
-    let input = std::fs::read_to_string(input_file).context("Could not read input file")?;
+    // the cache can't be combined with solving a single part or timing
+    if let Some(dir) = cache {
+        let input = read_input(input_file)?;
+        let cached = CachedSolution::load_or_solve::<T>(&input, dir)?;
 
-    let solution = T::solve(&input)
-        .map_err(Into::<anyhow::Error>::into)
-        .context("Failed to solve")?;
+        if json {
+            println!("{}", serde_json::to_string(&cached.solution)?);
+        } else {
+            println!("{}", cached.solution);
+        }
+
+        return Ok(());
+    }
+
+    match part {
+        Some(1) => {
+            let answer = _instance::<T>(input_file)?
+                .part_one()
+                .map_err(Into::<anyhow::Error>::into)
+                .context("Failed to solve part one")?;
+            print_part(1, &answer, json)
+        }
+        Some(2) => {
+            let answer = _instance::<T>(input_file)?
+                .part_two()
+                .map_err(Into::<anyhow::Error>::into)
+                .context("Failed to solve part two")?;
+            print_part(2, &answer, json)
+        }
+        _ if timing => {
+            let input = read_input(input_file)?;
+            let (solution, timing) = T::solve_timed(&input)
+                .map_err(Into::<anyhow::Error>::into)
+                .context("Failed to solve")?;
+
+            if json {
+                let mut out = serde_json::to_value(&solution)?;
+                out["timing"] = serde_json::json!({
+                    "parse_ns": timing.parse.as_nanos(),
+                    "part_one_ns": timing.part_one.as_nanos(),
+                    "part_two_ns": timing.part_two.as_nanos(),
+                });
+                println!("{}", out);
+            } else {
+                println!("{}", solution);
+                println!("parse (ms): {}", format_ms(timing.parse.as_nanos()));
+                println!("part 1 (ms): {}", format_ms(timing.part_one.as_nanos()));
+                println!("part 2 (ms): {}", format_ms(timing.part_two.as_nanos()));
+            }
+
+            Ok(())
+        }
+        _ => {
+            let solution = _solve::<T>(input_file)?;
+
+            if json {
+                println!("{}", serde_json::to_string(&solution)?);
+            } else {
+                println!("{}", solution);
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Solve the input `runs` times, printing the solution along with the min,
+/// median, and max solve times.
+fn _repeat<T>(input_file: &Path, json: bool, runs: u32) -> Result<()>
+where
+    T: Problem,
+    <T as Problem>::ProblemError: Into<anyhow::Error>,
+{
+    let input = read_input(input_file)?;
+    let mut times = Vec::with_capacity(runs as usize);
+    let mut solution = None;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let res = T::solve(&input)
+            .map_err(Into::<anyhow::Error>::into)
+            .context("Failed to solve")?;
+        times.push(start.elapsed().as_nanos());
+        solution = Some(res);
+    }
+
+    // runs is at least 1, so we know we have a solution
+    let solution = solution.expect("No solution");
+    times.sort();
+    let (min, median, max) = (times[0], times[times.len() / 2], times[times.len() - 1]);
 
     if json {
-        println!("{}", serde_json::to_string(&solution)?);
+        let mut out = serde_json::to_value(&solution)?;
+        out["repeat"] = serde_json::json!({
+            "runs": runs,
+            "min_ns": min,
+            "median_ns": median,
+            "max_ns": max,
+        });
+        println!("{}", out);
     } else {
         println!("{}", solution);
+        println!("min (ms): {}", format_ms(min));
+        println!("median (ms): {}", format_ms(median));
+        println!("max (ms): {}", format_ms(max));
+    }
+
+    Ok(())
+}
+
+/// Solve the input and check each part against the expected answer, if one
+/// was given.
+fn _verify<T>(input_file: &Path, expected: &[Option<String>; 2]) -> Result<()>
+where
+    T: Problem,
+    <T as Problem>::ProblemError: Into<anyhow::Error>,
+{
+    if expected[1].is_some() && !T::HAS_PART_TWO {
+        bail!("Day {} does not have a part two to compare against", T::DAY);
+    }
+
+    let solution = DaySolution::new::<T>(&_solve::<T>(input_file)?)?;
+    let mut mismatched = false;
+
+    for (part, (answer, expected)) in [&solution.part_one, &solution.part_two]
+        .into_iter()
+        .zip(expected.iter())
+        .enumerate()
+    {
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let answer = display_value(answer);
+        if answer == *expected {
+            println!("part {}: ok", part + 1);
+        } else {
+            println!("part {}: expected {}, got {}", part + 1, expected, answer);
+            mismatched = true;
+        }
+    }
+
+    if mismatched {
+        bail!("Answers did not match the expected answers");
     }
 
     Ok(())
 }
 
+/// The first two bytes of any gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Read the input from the specified file, or from stdin if the path is `-`.
+///
+/// Gzipped inputs are detected by their magic bytes and decompressed.
+fn read_input(input_file: &Path) -> Result<String> {
+    let raw = if input_file == Path::new("-") {
+        let mut raw = Vec::new();
+        std::io::stdin()
+            .read_to_end(&mut raw)
+            .context("Could not read input from stdin")?;
+        raw
+    } else {
+        std::fs::read(input_file).context("Could not read input file")?
+    };
+
+    if raw.starts_with(&GZIP_MAGIC) {
+        let mut input = String::new();
+        GzDecoder::new(raw.as_slice())
+            .read_to_string(&mut input)
+            .context("Could not decompress input")?;
+        Ok(input)
+    } else {
+        String::from_utf8(raw).context("Input was not valid utf-8")
+    }
+}
+
+fn _instance<T>(input_file: &Path) -> Result<T>
+where
+    T: Problem,
+    <T as Problem>::ProblemError: Into<anyhow::Error>,
+{
+    let input = read_input(input_file)?;
+
+    T::instance(&input)
+        .map_err(|e| Into::<anyhow::Error>::into(T::ProblemError::from(e)))
+        .context("Failed to parse input")
+}
+
+/// Print the answer for a single part in the same shape as a `Solution`.
+fn print_part<V: Display + Serialize>(part: u8, answer: &V, json: bool) -> Result<()> {
+    if json {
+        let key = if part == 1 { "part_one" } else { "part_two" };
+        let mut out = serde_json::Map::new();
+        out.insert(key.to_string(), serde_json::to_value(answer)?);
+        println!("{}", serde_json::Value::Object(out));
+    } else {
+        println!("part {}: {}", part, answer);
+    }
+
+    Ok(())
+}
+
+fn _solve<T>(input_file: &Path) -> Result<Solution<T::P1, T::P2>>
+where
+    T: Problem,
+    <T as Problem>::ProblemError: Into<anyhow::Error>,
+{
+    let input = read_input(input_file)?;
+
+    T::solve(&input)
+        .map_err(Into::<anyhow::Error>::into)
+        .context("Failed to solve")
+}
+
+fn _time<T>(input_file: &Path) -> Result<TimingBreakdown>
+where
+    T: Problem,
+    <T as Problem>::ProblemError: Into<anyhow::Error>,
+{
+    let input = read_input(input_file)?;
+
+    let (_, timing) = T::solve_timed(&input)
+        .map_err(Into::<anyhow::Error>::into)
+        .context("Failed to solve")?;
+
+    Ok(timing)
+}
+
+/// Locate `day-XXX-*/input.txt` (or `input.txt.gz`) for the given day in the
+/// specified directory.
+fn find_input(dir: &Path, day: usize) -> Result<Option<PathBuf>> {
+    let prefix = format!("day-{:03}-", day);
+
+    for entry in std::fs::read_dir(dir).context("Could not read inputs directory")? {
+        let entry = entry?;
+        if !entry.file_name().to_string_lossy().starts_with(&prefix) {
+            continue;
+        }
+
+        for name in ["input.txt", "input.txt.gz"] {
+            let input = entry.path().join(name);
+            if input.is_file() {
+                return Ok(Some(input));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// A solution for a particular day, with the answers converted to json values
+/// so that the solutions for different days can be collected together. Days
+/// without a real part two keep their placeholder `part_two` in json, but it
+/// is left out of the table.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct DaySolution {
+    day: usize,
+    title: &'static str,
+    part_one: serde_json::Value,
+    part_two: serde_json::Value,
+    #[serde(skip)]
+    has_part_two: bool,
+}
+
+impl DaySolution {
+    pub fn new<T: Problem>(solution: &Solution<T::P1, T::P2>) -> Result<Self> {
+        Ok(Self {
+            day: T::DAY,
+            title: T::TITLE,
+            part_one: serde_json::to_value(&solution.part_one)?,
+            part_two: serde_json::to_value(&solution.part_two)?,
+            has_part_two: T::HAS_PART_TWO,
+        })
+    }
+}
+
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+impl DaySolution {
+    fn row(&self) -> [String; 4] {
+        [
+            format!("{:03}", self.day),
+            self.title.to_string(),
+            display_value(&self.part_one),
+            if self.has_part_two {
+                display_value(&self.part_two)
+            } else {
+                String::default()
+            },
+        ]
+    }
+}
+
+/// The timing information for solving a particular day.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct DayTiming {
+    day: usize,
+    title: &'static str,
+    parse_ns: u128,
+    part_one_ns: u128,
+    part_two_ns: u128,
+    total_ns: u128,
+}
+
+impl DayTiming {
+    pub fn new<T: Problem>(timing: &TimingBreakdown) -> Self {
+        Self {
+            day: T::DAY,
+            title: T::TITLE,
+            parse_ns: timing.parse.as_nanos(),
+            part_one_ns: timing.part_one.as_nanos(),
+            part_two_ns: timing.part_two.as_nanos(),
+            total_ns: timing.total().as_nanos(),
+        }
+    }
+
+    fn row(&self) -> [String; 6] {
+        [
+            format!("{:03}", self.day),
+            self.title.to_string(),
+            format_ms(self.parse_ns),
+            format_ms(self.part_one_ns),
+            format_ms(self.part_two_ns),
+            format_ms(self.total_ns),
+        ]
+    }
+}
+
+fn format_ms(ns: u128) -> String {
+    format!("{:.5}", ns as f64 / 1_000_000.0)
+}
+
+/// Print the rows as a table with a header, left-aligning the day and title
+/// columns and right-aligning everything else.
+fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let header = header.map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in rows.iter() {
+        for (width, col) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(col.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(rows.iter()) {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .enumerate()
+            .map(|(i, (col, width))| {
+                if i < 2 {
+                    format!("{:<width$}", col)
+                } else {
+                    format!("{:>width$}", col)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
+
+/// The answers for a day, in a form that can be written to and read back from
+/// the answer cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedAnswers {
+    part_one: serde_json::Value,
+    part_two: serde_json::Value,
+}
+
+impl Display for CachedAnswers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "part 1: {}\npart 2: {}",
+            display_value(&self.part_one),
+            display_value(&self.part_two)
+        )
+    }
+}
+
+/// A cached solution, along with the hash of the input it was computed for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedSolution {
+    input_hash: String,
+    solution: CachedAnswers,
+}
+
+impl CachedSolution {
+    /// Load the solution for the given input from `{dir}/{day}.json`, or solve
+    /// the input and write the solution there if the cached one is missing or
+    /// was for a different input.
+    pub fn load_or_solve<T>(input: &str, dir: &Path) -> Result<Self>
+    where
+        T: Problem,
+        <T as Problem>::ProblemError: Into<anyhow::Error>,
+    {
+        let input_hash = format!("{:016x}", xxh3_64(input.as_bytes()));
+        let path = dir.join(format!("{}.json", T::DAY));
+
+        if let Ok(raw) = std::fs::read_to_string(&path) {
+            if let Ok(cached) = serde_json::from_str::<Self>(&raw) {
+                if cached.input_hash == input_hash {
+                    return Ok(cached);
+                }
+            }
+        }
+
+        let solution = DaySolution::new::<T>(
+            &T::solve(input)
+                .map_err(Into::<anyhow::Error>::into)
+                .context("Failed to solve")?,
+        )?;
+
+        let cached = Self {
+            input_hash,
+            solution: CachedAnswers {
+                part_one: solution.part_one,
+                part_two: solution.part_two,
+            },
+        };
+
+        std::fs::create_dir_all(dir).context("Could not create cache directory")?;
+        std::fs::write(&path, serde_json::to_string(&cached)?)
+            .context("Could not write cached solution")?;
+
+        Ok(cached)
+    }
+}
+
 /// Generate zsh completions
 #[derive(Debug, Args)]
 pub struct GenerateCompletions;
@@ -0,0 +1,314 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+fn aoc_command(args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    cmd.args(args)
+        .env_remove("AOC_DAY")
+        .env_remove("AOC_INPUT")
+        .env_remove("AOC_JSON");
+    cmd
+}
+
+fn aoc(args: &[&str]) -> Output {
+    aoc_command(args).output().expect("Failed to run aoc")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "aoc failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).expect("Output was not utf-8")
+}
+
+#[test]
+fn run_all() {
+    let inputs = fixtures().join("inputs");
+    let out = stdout(&aoc(&["run-all", inputs.to_str().unwrap()]));
+    let lines = out
+        .lines()
+        .map(|l| l.split_whitespace().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        lines,
+        vec![
+            vec!["day", "title", "part", "1", "part", "2"],
+            vec!["001", "trebuchet", "209", "281"],
+            vec!["002", "cube", "conundrum", "8", "2286"],
+        ]
+    );
+}
+
+#[test]
+fn run_all_json() {
+    let inputs = fixtures().join("inputs");
+    let out = stdout(&aoc(&["run-all", "--json", inputs.to_str().unwrap()]));
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+
+    assert_eq!(
+        value,
+        serde_json::json!([
+            {"day": 1, "title": "trebuchet", "part_one": 209, "part_two": 281},
+            {"day": 2, "title": "cube conundrum", "part_one": 8, "part_two": 2286},
+        ])
+    );
+}
+
+#[test]
+fn time_all_json() {
+    let inputs = fixtures().join("inputs");
+    let out = stdout(&aoc(&["time-all", "--json", inputs.to_str().unwrap()]));
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+
+    let days = value["days"].as_array().unwrap();
+    assert_eq!(days.len(), 2);
+
+    let day_one = days.iter().find(|d| d["day"] == 1).unwrap();
+    assert_eq!(day_one["title"], "trebuchet");
+    for key in ["parse_ns", "part_one_ns", "part_two_ns", "total_ns"] {
+        assert!(day_one[key].is_u64());
+    }
+
+    assert!(value["total_ns"].is_u64());
+}
+
+#[test]
+fn list() {
+    let out = stdout(&aoc(&["list"]));
+    let lines = out.lines().collect::<Vec<_>>();
+    assert!(lines.contains(&"001 trebuchet"));
+    assert!(lines.contains(&"025 snowverload"));
+
+    let out = stdout(&aoc(&["list", "--json"]));
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+    let days = value.as_array().unwrap();
+    assert_eq!(days[0], serde_json::json!({"day": 1, "title": "trebuchet"}));
+}
+
+#[test]
+fn run_single_part() {
+    let input = fixtures().join("inputs/day-001-trebuchet/input.txt");
+    let input = input.to_str().unwrap();
+
+    let out = stdout(&aoc(&["run", "1", input, "--part", "1"]));
+    assert_eq!(out.trim(), "part 1: 209");
+
+    let out = stdout(&aoc(&["run", "1", input, "--part", "2"]));
+    assert_eq!(out.trim(), "part 2: 281");
+
+    let out = stdout(&aoc(&["run", "1", input, "--part", "1", "--json"]));
+    assert_eq!(out.trim(), r#"{"part_one":209}"#);
+
+    assert!(!aoc(&["run", "1", input, "--part", "3"]).status.success());
+}
+
+#[test]
+fn run_from_stdin() {
+    let input = std::fs::read_to_string(fixtures().join("inputs/day-001-trebuchet/input.txt"))
+        .expect("Could not read fixture");
+
+    let mut child = aoc_command(&["run", "1", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to run aoc");
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    let out = stdout(&child.wait_with_output().unwrap());
+    assert_eq!(out.trim(), "part 1: 209\npart 2: 281");
+}
+
+#[test]
+fn verify() {
+    let input = fixtures().join("inputs/day-001-trebuchet/input.txt");
+    let input = input.to_str().unwrap();
+    let answers = fixtures().join("answers/day-001-trebuchet.json");
+    let answers = answers.to_str().unwrap();
+
+    let out = stdout(&aoc(&[
+        "verify",
+        "1",
+        input,
+        "--expect-1",
+        "209",
+        "--expect-2",
+        "281",
+    ]));
+    assert_eq!(out.trim(), "part 1: ok\npart 2: ok");
+
+    let out = stdout(&aoc(&["verify", "1", input, "--answers", answers]));
+    assert_eq!(out.trim(), "part 1: ok\npart 2: ok");
+
+    let out = stdout(&aoc(&["verify", "1", input, "--expect-2", "281"]));
+    assert_eq!(out.trim(), "part 2: ok");
+}
+
+#[test]
+fn verify_mismatch() {
+    let input = fixtures().join("inputs/day-001-trebuchet/input.txt");
+    let input = input.to_str().unwrap();
+    let answers = fixtures().join("answers/day-001-trebuchet.json");
+    let answers = answers.to_str().unwrap();
+
+    let output = aoc(&["verify", "1", input, "--expect-1", "210"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "part 1: expected 210, got 209"
+    );
+
+    let output = aoc(&["verify", "1", input, "-a", answers, "--expect-2", "1"]);
+    assert!(!output.status.success());
+
+    // no expected answers at all is an error
+    assert!(!aoc(&["verify", "1", input]).status.success());
+}
+
+#[test]
+fn no_part_two() {
+    // day 25 only has a part one, so there's nothing to verify part two against
+    let input = fixtures().join("inputs/day-001-trebuchet/input.txt");
+    let input = input.to_str().unwrap();
+
+    let output = aoc(&["verify", "25", input, "--expect-2", "1"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("does not have a part two"));
+}
+
+#[test]
+fn run_gzipped() {
+    let input = fixtures().join("day-001-trebuchet.txt.gz");
+
+    let out = stdout(&aoc(&["run", "1", input.to_str().unwrap()]));
+    assert_eq!(out.trim(), "part 1: 209\npart 2: 281");
+
+    let mut child = aoc_command(&["run", "1", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to run aoc");
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(&std::fs::read(input).unwrap())
+        .unwrap();
+
+    let out = stdout(&child.wait_with_output().unwrap());
+    assert_eq!(out.trim(), "part 1: 209\npart 2: 281");
+}
+
+#[test]
+fn run_with_timing() {
+    let input = fixtures().join("inputs/day-001-trebuchet/input.txt");
+    let input = input.to_str().unwrap();
+
+    let out = stdout(&aoc(&["run", "1", input, "--json"]));
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(value, serde_json::json!({"part_one": 209, "part_two": 281}));
+
+    let out = stdout(&aoc(&["run", "1", input, "--json", "--timing"]));
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(value["part_one"], 209);
+    assert_eq!(value["part_two"], 281);
+    for key in ["parse_ns", "part_one_ns", "part_two_ns"] {
+        assert!(value["timing"][key].is_u64());
+    }
+
+    let out = stdout(&aoc(&["run", "1", input, "--timing"]));
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(&lines[..2], &["part 1: 209", "part 2: 281"]);
+    assert!(lines[2].starts_with("parse (ms): "));
+
+    assert!(!aoc(&["run", "1", input, "--timing", "--part", "1"])
+        .status
+        .success());
+}
+
+#[test]
+fn run_with_cache() {
+    let input = fixtures().join("inputs/day-001-trebuchet/input.txt");
+    let input = input.to_str().unwrap();
+    let cache = Path::new(env!("CARGO_TARGET_TMPDIR")).join("answer-cache");
+    let _ = std::fs::remove_dir_all(&cache);
+
+    let out = stdout(&aoc(&[
+        "run",
+        "1",
+        input,
+        "--cache",
+        cache.to_str().unwrap(),
+    ]));
+    assert_eq!(out.trim(), "part 1: 209\npart 2: 281");
+
+    // replace the cached answer with a sentinel, which we should see on the
+    // next run if the cache is used
+    let cached_file = cache.join("1.json");
+    let mut cached: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&cached_file).unwrap()).unwrap();
+    cached["solution"]["part_one"] = serde_json::json!("sentinel");
+    std::fs::write(&cached_file, cached.to_string()).unwrap();
+
+    let cache = cache.to_str().unwrap();
+    let out = stdout(&aoc(&["run", "1", input, "--cache", cache, "--json"]));
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({"part_one": "sentinel", "part_two": 281})
+    );
+
+    // a different input should not use the cached answer
+    let other = Path::new(env!("CARGO_TARGET_TMPDIR")).join("other-input.txt");
+    std::fs::write(&other, "1abc2").unwrap();
+    let out = stdout(&aoc(&[
+        "run",
+        "1",
+        other.to_str().unwrap(),
+        "--cache",
+        cache,
+    ]));
+    assert_eq!(out.trim(), "part 1: 12\npart 2: 12");
+}
+
+#[test]
+fn run_repeated() {
+    let input = fixtures().join("inputs/day-001-trebuchet/input.txt");
+    let input = input.to_str().unwrap();
+
+    let out = stdout(&aoc(&["run", "1", input, "--repeat", "5"]));
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(&lines[..2], &["part 1: 209", "part 2: 281"]);
+
+    let times = lines[2..]
+        .iter()
+        .map(|l| l.rsplit(' ').next().unwrap().parse::<f64>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(times.len(), 3);
+    assert!(times[0] <= times[1] && times[1] <= times[2]);
+
+    let out = stdout(&aoc(&["run", "1", input, "--repeat", "5", "--json"]));
+    let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(value["part_one"], 209);
+    assert_eq!(value["repeat"]["runs"], 5);
+
+    assert!(!aoc(&["run", "1", input, "--repeat", "0"]).status.success());
+}
@@ -1,3 +1,3 @@
 pub mod problem;
 
-pub use problem::{Problem, Solution};
+pub use problem::{Example, Problem, ProblemMeta, Solution, TimedSolution, TimingBreakdown};
@@ -1,4 +1,9 @@
-use std::{fmt::Display, str::FromStr};
+use std::{
+    fmt::Display,
+    io::Read,
+    str::FromStr,
+    time::{Duration, Instant},
+};
 
 use serde::Serialize;
 
@@ -77,11 +82,44 @@ where
     }
 }
 
+/// The wall-clock time spent in each stage of a call to `Problem::solve_timed`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TimingBreakdown {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl TimingBreakdown {
+    /// The sum of the parse, part one, and part two durations.
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// Metadata describing a `Problem` that does not depend on its associated
+/// types, so that the metadata for every day can be collected together.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+pub struct ProblemMeta {
+    pub day: usize,
+    pub title: &'static str,
+}
+
+/// An example input paired with its expected solution.
+pub type Example<T, G> = (&'static str, Solution<T, G>);
+
+/// A solution paired with the timing information for producing it.
+pub type TimedSolution<T, G> = (Solution<T, G>, TimingBreakdown);
+
 pub trait Problem: FromStr {
     const DAY: usize;
     const TITLE: &'static str;
     const README: &'static str;
 
+    /// Whether `part_two` produces a real answer. Some days (like the last
+    /// one) only have a placeholder for part two, which tooling can omit.
+    const HAS_PART_TWO: bool = true;
+
     type ProblemError: Send + Sync + From<<Self as FromStr>::Err> + 'static;
     type P1: Display + Serialize + PartialEq;
     type P2: Display + Serialize + PartialEq;
@@ -93,9 +131,218 @@ pub trait Problem: FromStr {
         Self::from_str(raw_input)
     }
 
+    /// Solve both parts for an already-parsed instance.
+    ///
+    /// By default, this just calls `part_one` followed by `part_two`, but
+    /// implementors whose parts share work can override this to avoid doing it
+    /// twice.
+    fn solve_combined(&mut self) -> Result<Solution<Self::P1, Self::P2>, Self::ProblemError> {
+        Ok(Solution::new(self.part_one()?, self.part_two()?))
+    }
+
+    /// Like `solve_combined`, additionally recording how long each part took.
+    /// The `parse` duration of the breakdown is left at zero.
+    ///
+    /// By default, this times `part_one` and `part_two` individually.
+    /// Implementors overriding `solve_combined` can override this as well,
+    /// attributing the shared work to whichever part they see fit.
+    fn solve_combined_timed(
+        &mut self,
+    ) -> Result<TimedSolution<Self::P1, Self::P2>, Self::ProblemError> {
+        let start = Instant::now();
+        let part_one = self.part_one()?;
+        let part_one_time = start.elapsed();
+
+        let start = Instant::now();
+        let part_two = self.part_two()?;
+        let part_two_time = start.elapsed();
+
+        Ok((
+            Solution::new(part_one, part_two),
+            TimingBreakdown {
+                parse: Duration::ZERO,
+                part_one: part_one_time,
+                part_two: part_two_time,
+            },
+        ))
+    }
+
     fn solve(raw_input: &str) -> Result<Solution<Self::P1, Self::P2>, Self::ProblemError> {
         let mut inst = Self::instance(raw_input)?;
-        Ok(Solution::new(inst.part_one()?, inst.part_two()?))
+        inst.solve_combined()
+    }
+
+    /// Read the entirety of `reader` and solve the problem for the contents.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use aoc_plumbing::{Problem, Solution};
+    ///
+    /// struct LineCount(usize);
+    ///
+    /// impl FromStr for LineCount {
+    ///     type Err = std::io::Error;
+    ///
+    ///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+    ///         Ok(Self(s.lines().count()))
+    ///     }
+    /// }
+    ///
+    /// impl Problem for LineCount {
+    ///     const DAY: usize = 1;
+    ///     const TITLE: &'static str = "line count";
+    ///     const README: &'static str = "";
+    ///
+    ///     type ProblemError = std::io::Error;
+    ///     type P1 = usize;
+    ///     type P2 = usize;
+    ///
+    ///     fn part_one(&mut self) -> Result<Self::P1, Self::ProblemError> {
+    ///         Ok(self.0)
+    ///     }
+    ///
+    ///     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
+    ///         Ok(self.0 * 2)
+    ///     }
+    /// }
+    ///
+    /// let input: &[u8] = b"foo\nbar\nbaz";
+    /// let solution = LineCount::solve_from_reader(input).unwrap();
+    /// assert_eq!(solution, Solution::new(3, 6));
+    /// ```
+    fn solve_from_reader<R: Read>(
+        mut reader: R,
+    ) -> Result<Solution<Self::P1, Self::P2>, Self::ProblemError>
+    where
+        Self::ProblemError: From<std::io::Error>,
+    {
+        let mut raw_input = String::new();
+        reader.read_to_string(&mut raw_input)?;
+        Self::solve(&raw_input)
+    }
+
+    /// Solve the problem like `solve`, additionally recording how long parsing
+    /// and each of the parts took.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use std::{str::FromStr, time::Duration};
+    /// use aoc_plumbing::{Problem, Solution};
+    ///
+    /// struct Sleepy;
+    ///
+    /// impl FromStr for Sleepy {
+    ///     type Err = std::convert::Infallible;
+    ///
+    ///     fn from_str(_: &str) -> Result<Self, Self::Err> {
+    ///         std::thread::sleep(Duration::from_millis(1));
+    ///         Ok(Self)
+    ///     }
+    /// }
+    ///
+    /// impl Problem for Sleepy {
+    ///     const DAY: usize = 1;
+    ///     const TITLE: &'static str = "sleepy";
+    ///     const README: &'static str = "";
+    ///
+    ///     type ProblemError = std::convert::Infallible;
+    ///     type P1 = u8;
+    ///     type P2 = u8;
+    ///
+    ///     fn part_one(&mut self) -> Result<Self::P1, Self::ProblemError> {
+    ///         std::thread::sleep(Duration::from_millis(1));
+    ///         Ok(1)
+    ///     }
+    ///
+    ///     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
+    ///         std::thread::sleep(Duration::from_millis(1));
+    ///         Ok(2)
+    ///     }
+    /// }
+    ///
+    /// let (solution, timing) = Sleepy::solve_timed("").unwrap();
+    /// assert_eq!(solution, Solution::new(1, 2));
+    /// assert!(timing.parse >= Duration::from_millis(1));
+    /// assert!(timing.part_one >= Duration::from_millis(1));
+    /// assert!(timing.part_two >= Duration::from_millis(1));
+    /// assert_eq!(timing.total(), timing.parse + timing.part_one + timing.part_two);
+    /// ```
+    fn solve_timed(
+        raw_input: &str,
+    ) -> Result<TimedSolution<Self::P1, Self::P2>, Self::ProblemError> {
+        let start = Instant::now();
+        let mut inst = Self::instance(raw_input)?;
+        let parse = start.elapsed();
+
+        let (solution, mut timing) = inst.solve_combined_timed()?;
+        timing.parse = parse;
+
+        Ok((solution, timing))
+    }
+
+    /// The day and title of this problem as a `ProblemMeta`.
+    fn meta() -> ProblemMeta {
+        ProblemMeta {
+            day: <Self as Problem>::DAY,
+            title: <Self as Problem>::TITLE,
+        }
+    }
+
+    /// The example input from the puzzle description along with its expected
+    /// solution, if the implementation provides one.
+    fn example() -> Option<Example<Self::P1, Self::P2>> {
+        None
+    }
+
+    /// Solve the example, if there is one, and check the result against the
+    /// expected solution.
+    ///
+    /// # Usage
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use aoc_plumbing::{Example, Problem, Solution};
+    ///
+    /// struct WordCount(usize);
+    ///
+    /// impl FromStr for WordCount {
+    ///     type Err = std::convert::Infallible;
+    ///
+    ///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+    ///         Ok(Self(s.split_whitespace().count()))
+    ///     }
+    /// }
+    ///
+    /// impl Problem for WordCount {
+    ///     const DAY: usize = 1;
+    ///     const TITLE: &'static str = "word count";
+    ///     const README: &'static str = "";
+    ///
+    ///     type ProblemError = std::convert::Infallible;
+    ///     type P1 = usize;
+    ///     type P2 = usize;
+    ///
+    ///     fn part_one(&mut self) -> Result<Self::P1, Self::ProblemError> {
+    ///         Ok(self.0)
+    ///     }
+    ///
+    ///     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
+    ///         Ok(self.0 + 1)
+    ///     }
+    ///
+    ///     fn example() -> Option<Example<Self::P1, Self::P2>> {
+    ///         Some(("foo bar baz", Solution::new(3, 4)))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(WordCount::check_example(), Some(Ok(true)));
+    /// ```
+    fn check_example() -> Option<Result<bool, Self::ProblemError>> {
+        let (input, expected) = Self::example()?;
+        Some(Self::solve(input).map(|solution| solution == expected))
     }
 
     fn problem_label() -> String {
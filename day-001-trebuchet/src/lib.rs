@@ -1,6 +1,14 @@
 use std::str::FromStr;
 
-use aoc_plumbing::Problem;
+use aoc_plumbing::{Example, Problem, Solution};
+
+const EXAMPLE: &str = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
 
 fn extract_digit_from_slice(slice: &[u8]) -> u32 {
     if slice[0].is_ascii_digit() {
@@ -41,7 +49,8 @@ impl FromStr for Trebuchet {
         let mut lines = 0;
         let mut letter_lines = 0;
 
-        for l in s.trim().split('\n') {
+        // lines() strips a trailing '\r' as well, so CRLF input works too
+        for l in s.trim().lines() {
             let digits = l
                 .chars()
                 .filter_map(|ch| ch.to_digit(10))
@@ -98,12 +107,14 @@ impl Problem for Trebuchet {
         // it was easier to solve this as part of the parsing
         Ok(self.letter_lines)
     }
+
+    fn example() -> Option<Example<Self::P1, Self::P2>> {
+        Some((EXAMPLE, Solution::new(209, 281)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use aoc_plumbing::Solution;
-
     use super::*;
 
     #[test]
@@ -126,4 +137,34 @@ zoneight234
         let solution = Trebuchet::solve(input).unwrap();
         assert_eq!(solution, Solution::new(209, 281));
     }
+
+    #[test]
+    fn combined_matches_parts() {
+        let mut inst = Trebuchet::instance(EXAMPLE).unwrap();
+        let solution = inst.solve_combined().unwrap();
+
+        let mut inst = Trebuchet::instance(EXAMPLE).unwrap();
+        let expected = Solution::new(inst.part_one().unwrap(), inst.part_two().unwrap());
+        assert_eq!(solution, expected);
+        assert_eq!(solution, Solution::new(209, 281));
+    }
+
+    #[test]
+    fn meta() {
+        let meta = Trebuchet::meta();
+        assert_eq!(meta.day, 1);
+        assert_eq!(meta.title, "trebuchet");
+    }
+
+    #[test]
+    fn example_matches() {
+        assert_eq!(Trebuchet::check_example().map(Result::ok), Some(Some(true)));
+    }
+
+    #[test]
+    fn crlf() {
+        let input = EXAMPLE.replace('\n', "\r\n");
+        let solution = Trebuchet::solve(&input).unwrap();
+        assert_eq!(solution, Solution::new(209, 281));
+    }
 }
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use anyhow::anyhow;
-use aoc_plumbing::Problem;
+use aoc_plumbing::{Example, Problem, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -12,6 +12,12 @@ use nom::{
     IResult,
 };
 
+const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Color {
     RED,
@@ -70,6 +76,24 @@ impl Set {
     pub fn subset(&self, other: &Set) -> bool {
         self.r <= other.r && self.g <= other.g && self.b <= other.b
     }
+
+    /// Component-wise subtraction, stopping at zero.
+    pub fn saturating_sub(&self, other: &Set) -> Set {
+        Set {
+            r: self.r.saturating_sub(other.r),
+            g: self.g.saturating_sub(other.g),
+            b: self.b.saturating_sub(other.b),
+        }
+    }
+
+    /// Component-wise addition.
+    pub fn add(&self, other: &Set) -> Set {
+        Set {
+            r: self.r + other.r,
+            g: self.g + other.g,
+            b: self.b + other.b,
+        }
+    }
 }
 
 fn fold_set(input: &str) -> IResult<&str, Set> {
@@ -166,12 +190,14 @@ impl Problem for CubeConundrum {
     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
         Ok(self.p2)
     }
+
+    fn example() -> Option<Example<Self::P1, Self::P2>> {
+        Some((EXAMPLE, Solution::new(8, 2286)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use aoc_plumbing::Solution;
-
     use super::*;
 
     #[test]
@@ -192,4 +218,15 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
         let solution = CubeConundrum::solve(input).unwrap();
         assert_eq!(solution, Solution::new(8, 2286));
     }
+
+    #[test]
+    fn set_arithmetic() {
+        let a = Set { r: 3, g: 0, b: 7 };
+        let b = Set { r: 5, g: 2, b: 1 };
+
+        assert_eq!(a.add(&b), Set { r: 8, g: 2, b: 8 });
+        assert_eq!(a.saturating_sub(&b), Set { r: 0, g: 0, b: 6 });
+        assert_eq!(b.saturating_sub(&a), Set { r: 2, g: 2, b: 0 });
+        assert_eq!(a.add(&b).saturating_sub(&b), a);
+    }
 }
@@ -7,9 +7,36 @@ use aoc_std::{collections::Grid, directions::BoundedCardinalNeighbors, geometry:
 pub struct GearRatios {
     part_total: u32,
     gear_total: u32,
+    chars: Grid<char>,
 }
 
 impl GearRatios {
+    /// Every number in the schematic as `(row, start_col, end_col, value)`,
+    /// where `end_col` is inclusive.
+    pub fn number_spans(&self) -> Vec<(usize, usize, usize, u32)> {
+        let mut spans = Vec::default();
+        for (row, line) in self.chars.locations.iter().enumerate() {
+            let mut cur: Option<(usize, u32)> = None;
+            for (col, ch) in line.iter().enumerate() {
+                cur = match (cur, ch.to_digit(10)) {
+                    (Some((start, number)), Some(d)) => Some((start, number * 10 + d)),
+                    (None, Some(d)) => Some((col, d)),
+                    (Some((start, number)), None) => {
+                        spans.push((row, start, col - 1, number));
+                        None
+                    }
+                    (None, None) => None,
+                };
+            }
+
+            if let Some((start, number)) = cur {
+                spans.push((row, start, line.len() - 1, number));
+            }
+        }
+
+        spans
+    }
+
     // this is wonky, but there was more variance in this "common" functionality
     // than I originally thought.
     fn extract_numbers<I>(
@@ -114,6 +141,7 @@ impl FromStr for GearRatios {
         Ok(Self {
             part_total,
             gear_total,
+            chars,
         })
     }
 }
@@ -165,4 +193,23 @@ mod tests {
         let solution = GearRatios::solve(input).unwrap();
         assert_eq!(solution, Solution::new(4361, 467835));
     }
+
+    #[test]
+    fn number_spans() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let inst = GearRatios::instance(input).unwrap();
+        let spans = inst.number_spans();
+        assert_eq!(spans.len(), 10);
+        assert_eq!(spans[0], (0, 0, 2, 467));
+        assert_eq!(spans[1], (0, 5, 7, 114));
+    }
 }
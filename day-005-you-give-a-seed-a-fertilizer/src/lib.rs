@@ -35,6 +35,14 @@ impl RangeMapEntry {
             None
         }
     }
+
+    pub fn translate_reverse(&self, output: i64) -> Option<i64> {
+        if self.destination.contains_value(output) {
+            Some(self.source.start + output - self.destination.start)
+        } else {
+            None
+        }
+    }
 }
 
 fn parse_range_map_entry(input: &str) -> IResult<&str, RangeMapEntry> {
@@ -76,6 +84,35 @@ impl RangeMap {
 
         input
     }
+
+    /// The inverse of `translate`: map a destination value back to the source
+    /// value that produces it.
+    pub fn translate_reverse(&self, output: i64) -> i64 {
+        for e in self.entries.iter() {
+            if let Some(v) = e.translate_reverse(output) {
+                return v;
+            }
+        }
+
+        output
+    }
+
+    /// Like `translate_reverse`, but additionally returns how many consecutive
+    /// outputs, starting at `output`, are translated by the same offset.
+    fn translate_reverse_span(&self, output: i64) -> (i64, i64) {
+        let mut span = i64::MAX;
+        for e in self.entries.iter() {
+            if let Some(v) = e.translate_reverse(output) {
+                return (v, e.destination.end - output + 1);
+            }
+
+            if e.destination.start > output {
+                span = span.min(e.destination.start - output);
+            }
+        }
+
+        (output, span)
+    }
 }
 
 fn parse_map_mapping(input: &str) -> IResult<&str, (&str, &str)> {
@@ -191,6 +228,63 @@ impl YouGiveASeedAFertilizer {
 
         ranges.iter().map(|c| c.start).min().unwrap_or_default()
     }
+
+    /// An alternative to `lowest_location_number_range` that walks the
+    /// location space in ascending order, reverse translating each location to
+    /// its seed and checking if that seed is in one of the seed ranges.
+    ///
+    /// Because every map is piecewise linear, we don't need to check every
+    /// location: a span of locations that all reverse translate with the same
+    /// offset can be checked against the seed ranges at once.
+    ///
+    /// This assumes every map is a bijection, which holds for the puzzle
+    /// inputs but isn't checked, so `part_two` sticks with
+    /// `lowest_location_number_range`. Like that method, this returns 0 if no
+    /// location maps back into any of the seed ranges.
+    pub fn lowest_by_reverse_search(&self) -> i64 {
+        let seed_ranges = self
+            .seeds
+            .iter()
+            .tuples()
+            .map(|(start, len)| Interval::new(*start, *start + *len - 1))
+            .collect::<Vec<_>>();
+
+        let mut location = 0;
+        loop {
+            let mut seed = location;
+            let mut span = i64::MAX;
+            for map in self.range_maps.iter().rev() {
+                let (prev, prev_span) = map.translate_reverse_span(seed);
+                seed = prev;
+                span = span.min(prev_span);
+            }
+
+            // every location in location..(location + span) maps to a seed in
+            // seed..(seed + span), so find the first of those seeds we have
+            let mut offset: Option<i64> = None;
+            for range in seed_ranges.iter() {
+                if range.contains_value(seed) {
+                    return location;
+                }
+
+                if range.start > seed && range.start - seed < span {
+                    let delta = range.start - seed;
+                    offset = Some(offset.map_or(delta, |o| o.min(delta)));
+                }
+            }
+
+            if let Some(offset) = offset {
+                return location + offset;
+            }
+
+            if span == i64::MAX {
+                // there are no more seeds to find
+                return 0;
+            }
+
+            location += span;
+        }
+    }
 }
 
 impl FromStr for YouGiveASeedAFertilizer {
@@ -273,4 +367,69 @@ humidity-to-location map:
         let solution = YouGiveASeedAFertilizer::solve(input).unwrap();
         assert_eq!(solution, Solution::new(35, 46));
     }
+
+    #[test]
+    fn reverse_search() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let inst = YouGiveASeedAFertilizer::from_str(input).unwrap();
+        assert_eq!(inst.lowest_by_reverse_search(), 46);
+        assert_eq!(
+            inst.lowest_by_reverse_search(),
+            inst.lowest_location_number_range()
+        );
+    }
+
+    #[test]
+    fn reverse_translation() {
+        let (_, map) = parse_range_map(
+            "seed-to-soil map:
+50 98 2
+52 50 48",
+        )
+        .unwrap();
+
+        for entry in map.entries.iter() {
+            for v in [entry.source.start, entry.source.end] {
+                assert_eq!(map.translate_reverse(map.translate(v)), v);
+            }
+        }
+
+        assert_eq!(map.translate(79), 81);
+        assert_eq!(map.translate_reverse(81), 79);
+
+        // values outside of any entry map to themselves
+        assert_eq!(map.translate_reverse(10), 10);
+    }
 }
@@ -1,7 +1,6 @@
 use std::str::FromStr;
 
 use aoc_plumbing::Problem;
-use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
     character::complete::{self, alpha1, newline, space1},
@@ -41,6 +40,22 @@ impl WaitForIt {
 
         upper - lower + 1
     }
+
+    /// The time and record for the single race formed by concatenating the
+    /// digits of every race.
+    pub fn single_race(&self) -> (i64, i64) {
+        (concat_digits(&self.times), concat_digits(&self.records))
+    }
+}
+
+fn concat_digits(values: &[i64]) -> i64 {
+    values.iter().fold(0, |acc, &v| {
+        let mut shift = 10;
+        while shift <= v {
+            shift *= 10;
+        }
+        acc * shift + v
+    })
 }
 
 fn parse_numbers(input: &str) -> IResult<&str, Vec<i64>> {
@@ -79,8 +94,7 @@ impl Problem for WaitForIt {
     }
 
     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
-        let time: i64 = self.times.iter().join("").parse()?;
-        let record: i64 = self.records.iter().join("").parse()?;
+        let (time, record) = self.single_race();
         Ok(self.ways_to_beat(time, record))
     }
 }
@@ -106,4 +120,12 @@ Distance:  9  40  200";
         let solution = WaitForIt::solve(input).unwrap();
         assert_eq!(solution, Solution::new(288, 71503));
     }
+
+    #[test]
+    fn single_race() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+        let inst = WaitForIt::instance(input).unwrap();
+        assert_eq!(inst.single_race(), (71530, 940200));
+    }
 }
@@ -3,9 +3,8 @@ use std::str::FromStr;
 use anyhow::bail;
 use aoc_plumbing::Problem;
 use nom::{
-    character::complete::{self, alphanumeric1, newline},
+    character::complete::{self, alphanumeric1},
     combinator::{self, map_res},
-    multi::separated_list1,
     sequence::separated_pair,
     IResult,
 };
@@ -204,25 +203,52 @@ fn parse_hand(input: &str) -> IResult<&str, Hand> {
     )(input)
 }
 
-fn parse_hands(input: &str) -> IResult<&str, Vec<Hand>> {
-    let (input, mut hands) = separated_list1(newline, parse_hand)(input)?;
-
-    hands.sort();
-
-    Ok((input, hands))
-}
-
 #[derive(Debug, Clone)]
 pub struct CamelCards {
     hands: Vec<Hand>,
 }
 
+impl CamelCards {
+    /// Parse the hands one line at a time, deferring the sort until all of the
+    /// hands have been read. Blank lines are skipped.
+    pub fn from_lines<I, S>(lines: I) -> Result<Self, anyhow::Error>
+    where
+        I: Iterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut hands = Vec::with_capacity(lines.size_hint().0);
+
+        for line in lines {
+            let line = line.as_ref();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (_, hand) = parse_hand(line).map_err(|e| e.to_owned())?;
+            hands.push(hand);
+        }
+
+        hands.sort();
+
+        Ok(Self { hands })
+    }
+
+    /// The number of hands of each kind under the normal (non-joker) rules,
+    /// indexed by `HandKind as usize`.
+    pub fn kind_histogram(&self) -> [usize; 7] {
+        let mut histogram = [0; 7];
+        for hand in self.hands.iter() {
+            histogram[hand.kind as usize] += 1;
+        }
+        histogram
+    }
+}
+
 impl FromStr for CamelCards {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, hands) = parse_hands(s).map_err(|e| e.to_owned())?;
-        Ok(Self { hands })
+        Self::from_lines(s.lines())
     }
 }
 
@@ -283,4 +309,33 @@ QQQJA 483";
         let solution = CamelCards::solve(input).unwrap();
         assert_eq!(solution, Solution::new(6440, 5905));
     }
+
+    #[test]
+    fn from_lines() {
+        let lines = vec![
+            String::from("32T3K 765"),
+            String::from("T55J5 684"),
+            String::from("KK677 28"),
+            String::from("KTJJT 220"),
+            String::from("QQQJA 483"),
+        ];
+        let mut inst = CamelCards::from_lines(lines.into_iter()).unwrap();
+        assert_eq!(inst.part_one().unwrap(), 6440);
+        assert_eq!(inst.part_two().unwrap(), 5905);
+
+        assert!(CamelCards::from_lines(["32T3K 765", "nope"].iter()).is_err());
+    }
+
+    #[test]
+    fn kind_histogram() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        let inst = CamelCards::instance(input).unwrap();
+        let histogram = inst.kind_histogram();
+        assert_eq!(histogram, [0, 1, 2, 2, 0, 0, 0]);
+        assert_eq!(histogram[HandKind::TwoPair as usize], 2);
+    }
 }
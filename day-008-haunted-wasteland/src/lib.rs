@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use anyhow::anyhow;
 use aoc_plumbing::Problem;
 use aoc_std::directions::Relative;
 use nom::{
@@ -10,11 +11,23 @@ use nom::{
     sequence::{delimited, preceded, separated_pair},
     IResult,
 };
-use num_prime::nt_funcs::factorize64;
 use rayon::prelude::*;
-use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hash::FxHashMap;
 use xxhash_rust::xxh3::xxh3_64;
 
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// Just multiplying the distinct prime factors of the cycle lengths only works
+// if none of them share a repeated prime factor, so do this properly.
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Node {
     left: u64,
@@ -81,17 +94,26 @@ pub struct HauntedWasteland {
 }
 
 impl HauntedWasteland {
-    pub fn steps_from(&self, start: u64, end: u64) -> usize {
-        let mut count = 0;
+    /// The number of steps needed to get from the `start` node to the `end`
+    /// node.
+    ///
+    /// Returns `None` if either node does not exist or if `end` can never be
+    /// reached from `start`.
+    pub fn steps_from(&self, start: u64, end: u64) -> Option<usize> {
+        if !self.mapping.contains_key(&end) {
+            return None;
+        }
 
-        let insts = self.instructions.iter().cycle();
         let mut cur = start;
-        let mut cur_node = self.mapping.get(&start).unwrap();
+        let mut cur_node = self.mapping.get(&start)?;
 
-        #[allow(clippy::explicit_counter_loop)]
-        for inst in insts {
+        // once we've had the chance to visit every (node, instruction) pair, we
+        // must be stuck in a loop that doesn't contain the end
+        let limit = self.instructions.len() * self.mapping.len();
+
+        for (count, inst) in self.instructions.iter().cycle().take(limit + 1).enumerate() {
             if cur == end {
-                break;
+                return Some(count);
             }
 
             match inst {
@@ -100,36 +122,24 @@ impl HauntedWasteland {
                 _ => unreachable!(),
             }
 
-            cur_node = self.mapping.get(&cur).unwrap();
-            count += 1;
+            cur_node = self.mapping.get(&cur)?;
         }
 
-        count
+        None
     }
 
-    pub fn ghost_steps_from(&self) -> u64 {
-        let mut factors: FxHashSet<u64> = FxHashSet::default();
+    /// The number of steps needed to get from the node named `from` to the
+    /// node named `to`. See `steps_from`.
+    pub fn steps_between(&self, from: &str, to: &str) -> Option<usize> {
+        self.steps_from(xxh3_64(from.as_bytes()), xxh3_64(to.as_bytes()))
+    }
 
-        let factor_groups: Vec<_> = self
-            .mapping
+    pub fn ghost_steps_from(&self) -> u64 {
+        self.mapping
             .par_iter()
             .filter(|(_, v)| v.ends_with_a)
-            .map(|(k, _)| {
-                let count = self.get_first_instance(*k);
-                factorize64(count as u64)
-            })
-            .collect();
-
-        for g in factor_groups {
-            factors.extend(g.keys().copied());
-        }
-
-        let mut count = 1;
-        for factor in factors {
-            count *= factor;
-        }
-
-        count
+            .map(|(k, _)| self.get_first_instance(*k) as u64)
+            .reduce(|| 1, lcm)
     }
 
     /// This is making a massive assumption that we never hit Z multiple times
@@ -186,7 +196,8 @@ impl Problem for HauntedWasteland {
     fn part_one(&mut self) -> Result<Self::P1, Self::ProblemError> {
         let start = xxh3_64(b"AAA");
         let end = xxh3_64(b"ZZZ");
-        Ok(self.steps_from(start, end))
+        self.steps_from(start, end)
+            .ok_or_else(|| anyhow!("ZZZ cannot be reached from AAA"))
     }
 
     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
@@ -208,6 +219,13 @@ mod tests {
         assert_eq!(solution, Solution::new(12361, 18215611419223));
     }
 
+    #[test]
+    fn lcm_of_cycles() {
+        assert_eq!(lcm(12, 18), 36);
+        assert_eq!(lcm(4, 2), 4);
+        assert_eq!([2, 3, 4].into_iter().fold(1, lcm), 12);
+    }
+
     #[test]
     fn part_one_example() {
         let input = "RL
@@ -224,6 +242,28 @@ ZZZ = (ZZZ, ZZZ)";
         assert_eq!(ans, 2);
     }
 
+    #[test]
+    fn steps_between() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+        let inst = HauntedWasteland::instance(input).unwrap();
+        assert_eq!(inst.steps_between("AAA", "ZZZ"), Some(2));
+        assert_eq!(inst.steps_between("AAA", "CCC"), Some(1));
+        assert_eq!(inst.steps_between("ZZZ", "ZZZ"), Some(0));
+
+        // BBB only leads to the EEE loop
+        assert_eq!(inst.steps_between("BBB", "ZZZ"), None);
+        assert_eq!(inst.steps_between("QQQ", "ZZZ"), None);
+        assert_eq!(inst.steps_between("AAA", "QQQ"), None);
+    }
+
     #[test]
     fn part_two_example() {
         let input = "LR
@@ -1,5 +1,6 @@
 use std::{fmt::Display, str::FromStr};
 
+use anyhow::anyhow;
 use aoc_plumbing::Problem;
 use aoc_std::{
     collections::Grid,
@@ -19,18 +20,20 @@ pub enum Tile {
     Start,
 }
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '|' => Self::Vertical,
-            '-' => Self::Horizontal,
-            'L' => Self::NE90,
-            'J' => Self::NW90,
-            '7' => Self::SW90,
-            'F' => Self::SE90,
-            '.' => Self::Ground,
-            'S' => Self::Start,
-            _ => unreachable!("Invalid input {}", value),
+            '|' => Ok(Self::Vertical),
+            '-' => Ok(Self::Horizontal),
+            'L' => Ok(Self::NE90),
+            'J' => Ok(Self::NW90),
+            '7' => Ok(Self::SW90),
+            'F' => Ok(Self::SE90),
+            '.' => Ok(Self::Ground),
+            'S' => Ok(Self::Start),
+            _ => Err(anyhow!("Invalid tile: {}", value)),
         }
     }
 }
@@ -264,6 +267,48 @@ impl PipeMaze {
         // this actually always will evenly divide
         self.steps /= 2;
     }
+
+    /// An alternative to the shoelace-based interior count that marks every
+    /// tile on the loop, then scans each row, flipping between outside and
+    /// inside every time we cross a loop tile that connects to the north.
+    ///
+    /// This relies on the start tile having been resolved by `process_loop`.
+    pub fn interior_by_raycast(&self) -> usize {
+        let mut on_loop = vec![vec![false; self.maze.width()]; self.maze.height()];
+
+        let start_tile = self.maze.get(&self.start).copied().unwrap_or(Tile::Start);
+        let facing = match start_tile {
+            Tile::Vertical | Tile::NE90 | Tile::NW90 => Cardinal::North,
+            Tile::SW90 | Tile::SE90 => Cardinal::South,
+            Tile::Horizontal => Cardinal::East,
+            _ => unreachable!("The start tile should have been resolved"),
+        };
+
+        let mut actor = Actor::new(self.start, facing, start_tile);
+        loop {
+            on_loop[actor.location.row][actor.location.col] = true;
+            actor.advance(&self.maze);
+            if actor.location == self.start {
+                break;
+            }
+        }
+
+        let mut num_inside = 0;
+        for (row, tiles) in self.maze.locations.iter().enumerate() {
+            let mut inside = false;
+            for (col, tile) in tiles.iter().enumerate() {
+                if on_loop[row][col] {
+                    if matches!(tile, Tile::Vertical | Tile::NE90 | Tile::NW90) {
+                        inside = !inside;
+                    }
+                } else if inside {
+                    num_inside += 1;
+                }
+            }
+        }
+
+        num_inside
+    }
 }
 
 impl FromStr for PipeMaze {
@@ -279,15 +324,15 @@ impl FromStr for PipeMaze {
                 line.chars()
                     .enumerate()
                     .map(|(col, ch)| {
-                        let t = Tile::from(ch);
+                        let t = Tile::try_from(ch)?;
                         if t == Tile::Start {
                             start = Location::new(row, col);
                         }
-                        t
+                        Ok(t)
                     })
-                    .collect::<Vec<_>>()
+                    .collect::<Result<Vec<_>, Self::Err>>()
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
         let maze = Grid::new(tiles);
 
         let mut s = Self {
@@ -358,6 +403,7 @@ LJ...";
         let mut inst = PipeMaze::instance(input).unwrap();
         inst.part_one().unwrap();
         assert_eq!(inst.part_two().unwrap(), 4);
+        assert_eq!(inst.interior_by_raycast(), 4);
     }
 
     #[test]
@@ -376,6 +422,7 @@ L--J.L7...LJS7F-7L7.
         let mut inst = PipeMaze::instance(input).unwrap();
         inst.part_one().unwrap();
         assert_eq!(inst.part_two().unwrap(), 8);
+        assert_eq!(inst.interior_by_raycast(), 8);
     }
 
     #[test]
@@ -394,5 +441,12 @@ L7JLJL-JLJLJL--JLJ.L";
         let mut inst = PipeMaze::instance(input).unwrap();
         inst.part_one().unwrap();
         assert_eq!(inst.part_two().unwrap(), 10);
+        assert_eq!(inst.interior_by_raycast(), 10);
+    }
+
+    #[test]
+    fn invalid_tile() {
+        assert!(Tile::try_from('X').is_err());
+        assert!(PipeMaze::instance("S-7\n|X|\nL-J").is_err());
     }
 }
@@ -26,6 +26,20 @@ impl Spring {
             groups,
         }
     }
+
+    /// The number of arrangements once this spring is unfolded five times.
+    pub fn long_arrangements(&self) -> usize {
+        let long_key = [&self.key].iter().cycle().take(5).join("?");
+        let long_groups: Vec<_> = self
+            .groups
+            .iter()
+            .copied()
+            .cycle()
+            .take(5 * self.groups.len())
+            .collect();
+
+        fast_arrangements(long_key.as_bytes(), &long_groups)
+    }
 }
 
 fn parse_groups(input: &str) -> IResult<&str, Vec<u8>> {
@@ -213,6 +227,56 @@ pub fn arrangements(
     num_arrangements
 }
 
+/// Enumerate every concrete assignment of `#` and `.` to `input` that is
+/// consistent with `groups`.
+///
+/// This explores every possibility, so it is exponential in the number of `?`
+/// in the input. It's only suitable for inspecting small springs; use
+/// `fast_arrangements` if you only need the count.
+pub fn enumerate_arrangements(input: &[u8], groups: &[u8]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut cur = Vec::with_capacity(input.len());
+    enumerate_recur(input, groups, &mut cur, &mut out);
+    out
+}
+
+fn enumerate_recur(input: &[u8], groups: &[u8], cur: &mut Vec<u8>, out: &mut Vec<String>) {
+    let Some(first) = input.first() else {
+        if groups.is_empty() {
+            // we only ever push '#' and '.', so this is always valid
+            out.push(String::from_utf8_lossy(cur).into_owned());
+        }
+        return;
+    };
+
+    if *first != b'#' {
+        cur.push(b'.');
+        enumerate_recur(&input[1..], groups, cur, out);
+        cur.pop();
+    }
+
+    if *first != b'.' {
+        if let [v, remaining_groups @ ..] = groups {
+            let count = *v as usize;
+            let fits = input.len() >= count
+                && input[..count].iter().all(|ch| *ch != b'.')
+                && input.get(count).filter(|ch| **ch == b'#').is_none();
+
+            if fits {
+                let prev_len = cur.len();
+                cur.resize(prev_len + count, b'#');
+                if input.len() > count {
+                    cur.push(b'.');
+                    enumerate_recur(&input[(count + 1)..], remaining_groups, cur, out);
+                } else {
+                    enumerate_recur(&[], remaining_groups, cur, out);
+                }
+                cur.truncate(prev_len);
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct HotSprings {
     springs: Vec<Spring>,
@@ -227,21 +291,26 @@ impl HotSprings {
     }
 
     pub fn count_long_arrangements(&self) -> usize {
+        self.springs.par_iter().map(Spring::long_arrangements).sum()
+    }
+
+    /// The index and part two arrangement count of the spring with the most
+    /// arrangements. Ties go to the earliest spring.
+    pub fn max_arrangement_spring(&self) -> (usize, usize) {
         self.springs
             .par_iter()
-            .map(|s| {
-                let long_key = [&s.key].iter().cycle().take(5).join("?");
-                let long_groups: Vec<_> = s
-                    .groups
-                    .iter()
-                    .copied()
-                    .cycle()
-                    .take(5 * s.groups.len())
-                    .collect();
-
-                fast_arrangements(long_key.as_bytes(), &long_groups)
-            })
-            .sum()
+            .map(Spring::long_arrangements)
+            .enumerate()
+            .reduce(
+                || (0, 0),
+                |a, b| {
+                    if b.1 > a.1 || (b.1 == a.1 && b.0 < a.0) {
+                        b
+                    } else {
+                        a
+                    }
+                },
+            )
     }
 }
 
@@ -297,4 +366,42 @@ mod tests {
         let solution = HotSprings::solve(input).unwrap();
         assert_eq!(solution, Solution::new(21, 525152));
     }
+
+    #[test]
+    fn enumeration() {
+        assert_eq!(
+            enumerate_arrangements(b"???.###", &[1, 1, 3]),
+            vec!["#.#.###".to_string()]
+        );
+
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        let (_, springs) = parse_springs(input).unwrap();
+        for spring in springs {
+            let found = enumerate_arrangements(spring.key.as_bytes(), &spring.groups);
+            assert_eq!(
+                found.len(),
+                fast_arrangements(spring.key.as_bytes(), &spring.groups)
+            );
+            for arrangement in found {
+                assert_eq!(arrangement.len(), spring.key.len());
+            }
+        }
+    }
+
+    #[test]
+    fn max_arrangement_spring() {
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+        let inst = HotSprings::instance(input).unwrap();
+        assert_eq!(inst.max_arrangement_spring(), (5, 506250));
+    }
 }
@@ -221,16 +221,21 @@ impl BitDish {
             .sum()
     }
 
+    /// Perform a single spin cycle, returning the north load afterwards.
+    fn spin_cycle(&mut self) -> u32 {
+        self.tilt_north();
+        self.tilt_west();
+        self.tilt_south();
+        self.tilt_east();
+
+        self.total_load()
+    }
+
     pub fn cycle(&mut self, count: usize) -> u32 {
         let mut cache: FxHashMap<u128, usize> = FxHashMap::default();
         let mut loads: Vec<u32> = Vec::with_capacity(500);
         for cycle_idx in 0..count {
-            self.tilt_north();
-            self.tilt_west();
-            self.tilt_south();
-            self.tilt_east();
-
-            let load = self.total_load();
+            let load = self.spin_cycle();
 
             loads.push(load);
 
@@ -260,6 +265,12 @@ impl BitDish {
         unreachable!("how did this happen?")
     }
 
+    /// The north load after each of the first `cycles` spin cycles, without
+    /// any cycle detection.
+    pub fn load_trace(&mut self, cycles: usize) -> Vec<u32> {
+        (0..cycles).map(|_| self.spin_cycle()).collect()
+    }
+
     fn tilt_north(&mut self) {
         let mut rows = VecDeque::from_iter(1..self.height);
 
@@ -361,9 +372,28 @@ impl FromStr for BitDish {
     }
 }
 
+#[derive(Debug, Clone)]
+enum Backend {
+    Bit(BitDish),
+    Interval(Dish),
+}
+
 #[derive(Debug, Clone)]
 pub struct ParabolicReflectorDish {
-    dish: BitDish,
+    dish: Backend,
+}
+
+impl ParabolicReflectorDish {
+    /// Solve using the original interval-based [`Dish`] instead of the default
+    /// [`BitDish`]. The answers are the same, this is only useful for
+    /// comparing the two.
+    pub fn with_interval_backend(s: &str) -> Result<Self, anyhow::Error> {
+        let dish = Dish::from_str(s)?;
+
+        Ok(Self {
+            dish: Backend::Interval(dish),
+        })
+    }
 }
 
 impl FromStr for ParabolicReflectorDish {
@@ -372,7 +402,9 @@ impl FromStr for ParabolicReflectorDish {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let dish = BitDish::from_str(s)?;
 
-        Ok(Self { dish })
+        Ok(Self {
+            dish: Backend::Bit(dish),
+        })
     }
 }
 
@@ -386,13 +418,25 @@ impl Problem for ParabolicReflectorDish {
     type P2 = u32;
 
     fn part_one(&mut self) -> Result<Self::P1, Self::ProblemError> {
-        let mut dish = self.dish.clone();
-        dish.tilt_north();
-        Ok(dish.total_load())
+        match &self.dish {
+            Backend::Bit(dish) => {
+                let mut dish = dish.clone();
+                dish.tilt_north();
+                Ok(dish.total_load())
+            }
+            Backend::Interval(dish) => {
+                let mut dish = dish.clone();
+                dish.tilt_north();
+                Ok(dish.total_load_p1())
+            }
+        }
     }
 
     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
-        Ok(self.dish.cycle(1_000_000_000))
+        match &mut self.dish {
+            Backend::Bit(dish) => Ok(dish.cycle(1_000_000_000)),
+            Backend::Interval(dish) => Ok(dish.cycle(1_000_000_000)),
+        }
     }
 }
 
@@ -425,4 +469,40 @@ O.#..O.#.#
         let solution = ParabolicReflectorDish::solve(input).unwrap();
         assert_eq!(solution, Solution::new(136, 64));
     }
+
+    #[test]
+    fn interval_backend() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let mut bit = ParabolicReflectorDish::instance(input).unwrap();
+        let mut interval = ParabolicReflectorDish::with_interval_backend(input).unwrap();
+        assert_eq!(bit.part_one().unwrap(), 136);
+        assert_eq!(interval.part_one().unwrap(), 136);
+        assert_eq!(bit.part_two().unwrap(), 64);
+        assert_eq!(interval.part_two().unwrap(), 64);
+    }
+
+    #[test]
+    fn load_trace() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+        let mut dish = BitDish::from_str(input).unwrap();
+        assert_eq!(dish.load_trace(3), vec![87, 69, 69]);
+    }
 }
@@ -133,6 +133,19 @@ impl<'a> HASHMap<'a> {
         self.buckets[bucket].remove(label);
     }
 
+    fn apply(&mut self, inst: &Instruction<'a>) {
+        match inst.op {
+            Op::Remove => self.remove(inst.bucket, inst.label),
+            Op::Assign(v) => self.insert(
+                inst.bucket,
+                Entry {
+                    label: inst.label,
+                    focal: v,
+                },
+            ),
+        }
+    }
+
     pub fn focusing_power(&self) -> usize {
         self.buckets
             // .par_iter()
@@ -141,12 +154,30 @@ impl<'a> HASHMap<'a> {
             .map(|(bucket_idx, bucket)| (bucket_idx + 1) * bucket.focal_sum())
             .sum()
     }
+
+    /// The focusing power contributed by each of the 256 boxes, in box order.
+    pub fn box_powers(&self) -> Vec<usize> {
+        self.buckets
+            .iter()
+            .enumerate()
+            .map(|(bucket_idx, bucket)| (bucket_idx + 1) * bucket.focal_sum())
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct LensLibrary {
     p1: u32,
     p2: usize,
+    box_powers: Vec<usize>,
+}
+
+impl LensLibrary {
+    /// The focusing power contributed by each of the 256 boxes once every
+    /// step has been applied, in box order.
+    pub fn box_powers(&self) -> &[usize] {
+        &self.box_powers
+    }
 }
 
 impl FromStr for LensLibrary {
@@ -161,20 +192,13 @@ impl FromStr for LensLibrary {
                 .iter()
                 .fold(0, |acc, ch| ((acc + *ch as u32) * 17) % 256);
             let (_, inst) = parse_instruction(step).map_err(|e| e.to_owned())?;
-            match inst.op {
-                Op::Remove => hm.remove(inst.bucket, inst.label),
-                Op::Assign(v) => hm.insert(
-                    inst.bucket,
-                    Entry {
-                        label: inst.label,
-                        focal: v,
-                    },
-                ),
-            }
+            hm.apply(&inst);
         }
+        let box_powers = hm.box_powers();
         Ok(Self {
             p1,
-            p2: hm.focusing_power(),
+            p2: box_powers.iter().sum(),
+            box_powers,
         })
     }
 }
@@ -217,4 +241,16 @@ mod tests {
         let solution = LensLibrary::solve(input).unwrap();
         assert_eq!(solution, Solution::new(1320, 145));
     }
+
+    #[test]
+    fn box_powers() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let inst = LensLibrary::instance(input).unwrap();
+
+        let powers = inst.box_powers();
+        assert_eq!(powers.len(), 256);
+        assert_eq!(powers.iter().sum::<usize>(), 145);
+        assert_eq!(powers[0], 5);
+        assert_eq!(powers[3], 140);
+    }
 }
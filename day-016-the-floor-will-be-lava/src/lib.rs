@@ -1,5 +1,6 @@
 use std::{ops::BitOrAssign, str::FromStr};
 
+use anyhow::{anyhow, bail};
 use aoc_plumbing::Problem;
 use aoc_std::{collections::Grid, directions::Cardinal, geometry::Location};
 use nom::{
@@ -9,6 +10,7 @@ use nom::{
     IResult,
 };
 use rayon::prelude::*;
+use rustc_hash::FxHashMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Tile {
@@ -19,15 +21,57 @@ pub enum Tile {
     VertSplit,
 }
 
+impl Tile {
+    /// Whether a beam travelling in the given direction is split by this tile.
+    pub fn splits(&self, facing: Cardinal) -> bool {
+        matches!(
+            (self, facing),
+            (Self::HorizSplit, Cardinal::North | Cardinal::South)
+                | (Self::VertSplit, Cardinal::East | Cardinal::West)
+        )
+    }
+
+    /// The directions of the pair of beams this tile emits when it splits one.
+    fn split_facings(&self) -> [Cardinal; 2] {
+        match self {
+            Self::HorizSplit => [Cardinal::East, Cardinal::West],
+            _ => [Cardinal::North, Cardinal::South],
+        }
+    }
+
+    /// The direction a beam leaves this tile in, ignoring splitting.
+    pub fn reflect(&self, facing: Cardinal) -> Cardinal {
+        match (self, facing) {
+            (Self::MirrorF, Cardinal::North) => Cardinal::East,
+            (Self::MirrorF, Cardinal::South) => Cardinal::West,
+            (Self::MirrorF, Cardinal::East) => Cardinal::North,
+            (Self::MirrorF, Cardinal::West) => Cardinal::South,
+            (Self::MirrorB, Cardinal::North) => Cardinal::West,
+            (Self::MirrorB, Cardinal::South) => Cardinal::East,
+            (Self::MirrorB, Cardinal::East) => Cardinal::South,
+            (Self::MirrorB, Cardinal::West) => Cardinal::North,
+            _ => facing,
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '/' => Ok(Self::MirrorF),
+            '\\' => Ok(Self::MirrorB),
+            '|' => Ok(Self::VertSplit),
+            '-' => Ok(Self::HorizSplit),
+            _ => Err(anyhow!("Invalid tile: {}", value)),
+        }
+    }
+}
+
 fn parse_tile(input: &str) -> IResult<&str, Tile> {
-    combinator::map(complete::one_of(".|/\\-"), |ch| match ch {
-        '.' => Tile::Empty,
-        '/' => Tile::MirrorF,
-        '\\' => Tile::MirrorB,
-        '|' => Tile::VertSplit,
-        '-' => Tile::HorizSplit,
-        _ => unreachable!(),
-    })(input)
+    combinator::map_res(complete::none_of("\n"), Tile::try_from)(input)
 }
 
 fn parse_tile_row(input: &str) -> IResult<&str, Vec<Tile>> {
@@ -76,13 +120,38 @@ impl EnergizedSet {
     pub fn contains(&self, location: &Location) -> bool {
         self.rows[location.row] & 1_u128 << location.col != 0
     }
+
+    /// Add every location in `other` to this set, returning whether that
+    /// added anything new.
+    pub fn merge(&mut self, other: &Self) -> bool {
+        let mut changed = false;
+        for (row, bits) in self.rows.iter_mut().zip(other.rows.iter()) {
+            let merged = *row | bits;
+            changed |= merged != *row;
+            *row = merged;
+        }
+        changed
+    }
+
+    /// Every energized location, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = Location> + '_ {
+        self.rows.iter().enumerate().flat_map(|(row, bits)| {
+            (0..u128::BITS as usize)
+                .filter(move |col| bits & 1_u128 << col != 0)
+                .map(move |col| Location::new(row, col))
+        })
+    }
 }
 
 impl BitOrAssign for EnergizedSet {
     fn bitor_assign(&mut self, rhs: Self) {
-        for i in 0..self.rows.len() {
-            self.rows[i] |= rhs.rows[i];
-        }
+        *self |= &rhs;
+    }
+}
+
+impl BitOrAssign<&EnergizedSet> for EnergizedSet {
+    fn bitor_assign(&mut self, rhs: &EnergizedSet) {
+        self.merge(rhs);
     }
 }
 
@@ -134,6 +203,22 @@ impl VisistedMap {
     }
 }
 
+/// The tiles energized by every splitter once it splits a beam, including
+/// everything reachable from the beams it emits.
+///
+/// A splitter emits the same pair of beams regardless of which side it was
+/// hit from, so this is keyed on the splitter's location alone.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PropagateCache {
+    reachable: FxHashMap<Location, EnergizedSet>,
+}
+
+impl PropagateCache {
+    pub fn get(&self, splitter: &Location) -> Option<&EnergizedSet> {
+        self.reachable.get(splitter)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TheFloorWillBeLava {
     grid: Grid<Tile>,
@@ -141,6 +226,27 @@ pub struct TheFloorWillBeLava {
 
 impl TheFloorWillBeLava {
     pub fn propagate(&self, start: Particle) -> EnergizedSet {
+        self.propagate_with(start, |_, _| {})
+    }
+
+    /// Propagate the beam from the given start, returning the locations in
+    /// the order they were first energized.
+    pub fn propagate_trace(&self, start: Particle) -> Vec<Location> {
+        let mut trace = Vec::default();
+        self.propagate_with(start, |energized, location| {
+            if !energized.contains(location) {
+                trace.push(*location);
+            }
+        });
+        trace
+    }
+
+    /// Propagate the beam from the given start, calling `on_energize` with the
+    /// current energized set just before each location is added to it.
+    fn propagate_with<F>(&self, start: Particle, mut on_energize: F) -> EnergizedSet
+    where
+        F: FnMut(&EnergizedSet, &Location),
+    {
         // let mut seen: FxHashSet<Particle> = FxHashSet::default();
         let mut seen = VisistedMap::new(self.grid.height());
         let mut energized = EnergizedSet::new(self.grid.height());
@@ -153,6 +259,7 @@ impl TheFloorWillBeLava {
                 continue;
             }
             seen.add(&beam);
+            on_energize(&energized, &beam.location);
             energized.add(&beam.location);
 
             match tile {
@@ -210,7 +317,151 @@ impl TheFloorWillBeLava {
         energized
     }
 
+    /// Follow a single beam until it leaves the grid, repeats itself, or hits
+    /// a splitter that would split it, returning the tiles it energized and
+    /// the location of that splitter, if any.
+    pub fn trace_segment(&self, start: Particle) -> (EnergizedSet, Option<Location>) {
+        self.trace_segment_with(start, |_| {})
+    }
+
+    /// Like `trace_segment`, calling `on_visit` with each location as the beam
+    /// passes through it.
+    fn trace_segment_with<F>(
+        &self,
+        start: Particle,
+        mut on_visit: F,
+    ) -> (EnergizedSet, Option<Location>)
+    where
+        F: FnMut(&Location),
+    {
+        let mut seen = VisistedMap::new(self.grid.height());
+        let mut energized = EnergizedSet::new(self.grid.height());
+        let mut beam = start;
+
+        loop {
+            let tile = self.grid.get(&beam.location).unwrap();
+            if tile.splits(beam.facing) {
+                return (energized, Some(beam.location));
+            }
+
+            // a beam that isn't split can loop back through a splitter from
+            // the side that lets it pass
+            if seen.contains(&beam) {
+                return (energized, None);
+            }
+            seen.add(&beam);
+            on_visit(&beam.location);
+            energized.add(&beam.location);
+
+            beam.facing = tile.reflect(beam.facing);
+            match self.grid.cardinal_neighbor(&beam.location, beam.facing) {
+                Some((next, _)) => beam.location = next,
+                None => return (energized, None),
+            }
+        }
+    }
+
+    /// Every splitter in the grid, in row-major order.
+    fn splitters(&self) -> Vec<(Location, Tile)> {
+        let mut splitters = Vec::default();
+        for (row, tiles) in self.grid.locations.iter().enumerate() {
+            for (col, tile) in tiles.iter().enumerate() {
+                if matches!(tile, Tile::HorizSplit | Tile::VertSplit) {
+                    splitters.push((Location::new(row, col), *tile));
+                }
+            }
+        }
+        splitters
+    }
+
+    pub fn propagate_cache(&self) -> PropagateCache {
+        let height = self.grid.height();
+
+        let splitters = self.splitters();
+        let splitter_idx = splitters
+            .iter()
+            .enumerate()
+            .map(|(idx, (loc, _))| (*loc, idx))
+            .collect::<FxHashMap<_, _>>();
+
+        // every splitter is connected to at most two other splitters by the
+        // segments it emits, so trace those once
+        let (mut reachable, next): (Vec<_>, Vec<_>) = splitters
+            .par_iter()
+            .map(|(loc, tile)| {
+                let mut energized = EnergizedSet::new(height);
+                energized.add(loc);
+                let mut next = Vec::with_capacity(2);
+
+                for facing in tile.split_facings() {
+                    if let Some((location, _)) = self.grid.cardinal_neighbor(loc, facing) {
+                        let (segment, end) = self.trace_segment(Particle { location, facing });
+                        energized |= segment;
+                        if let Some(end) = end {
+                            next.push(splitter_idx[&end]);
+                        }
+                    }
+                }
+
+                (energized, next)
+            })
+            .unzip();
+
+        // splitters can feed each other in cycles, so rather than a single
+        // pass we keep merging in the sets of the downstream splitters until
+        // nothing changes
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (idx, downstream) in next.iter().enumerate() {
+                for n in downstream.iter().copied() {
+                    if n == idx {
+                        continue;
+                    }
+
+                    // borrow the two sets separately so we can merge in place
+                    let (lower, upper) = reachable.split_at_mut(idx.max(n));
+                    let (dest, src) = if idx < n {
+                        (&mut lower[idx], &upper[0])
+                    } else {
+                        (&mut upper[0], &lower[n])
+                    };
+                    changed |= dest.merge(src);
+                }
+            }
+        }
+
+        PropagateCache {
+            reachable: splitters
+                .into_iter()
+                .map(|(loc, _)| loc)
+                .zip(reachable)
+                .collect(),
+        }
+    }
+
+    pub fn propagate_cached(&self, start: Particle, cache: &PropagateCache) -> EnergizedSet {
+        let (mut energized, end) = self.trace_segment(start);
+
+        if let Some(reachable) = end.and_then(|loc| cache.get(&loc)) {
+            energized |= reachable;
+        }
+
+        energized
+    }
+
     pub fn propagate_all(&self) -> usize {
+        let cache = self.propagate_cache();
+
+        self.starting_particles()
+            .into_par_iter()
+            .map(|p| self.propagate_cached(p, &cache).count())
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Every beam entering the grid from an edge.
+    pub fn starting_particles(&self) -> Vec<Particle> {
         let height = self.grid.height();
         let width = self.grid.width();
         let mut starting_particles = Vec::with_capacity((width + height) * 2);
@@ -238,10 +489,6 @@ impl TheFloorWillBeLava {
         }
 
         starting_particles
-            .into_par_iter()
-            .map(|p| self.propagate(p).count())
-            .max()
-            .unwrap_or_default()
     }
 }
 
@@ -249,7 +496,16 @@ impl FromStr for TheFloorWillBeLava {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, tiles) = parse_tile_rows(s).map_err(|e| e.to_owned())?;
+        let (rem, tiles) = parse_tile_rows(s).map_err(|e| e.to_owned())?;
+
+        // the parser stops at the first tile it doesn't recognize, so anything
+        // left over means the input was malformed
+        if !rem.trim().is_empty() {
+            bail!(
+                "Unexpected input: {}",
+                rem.lines().next().unwrap_or_default()
+            );
+        }
 
         Ok(Self {
             grid: Grid::new(tiles),
@@ -281,6 +537,44 @@ mod tests {
 
     use super::*;
 
+    const EXAMPLE: &str = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#;
+
+    /// The number of tiles visited while propagating every starting beam,
+    /// without and with the `PropagateCache` respectively. The cached count
+    /// includes the visits made while building the cache.
+    fn tile_visits(inst: &TheFloorWillBeLava) -> (usize, usize) {
+        let starts = inst.starting_particles();
+
+        let mut uncached = 0;
+        for p in starts.iter() {
+            inst.propagate_with(*p, |_, _| uncached += 1);
+        }
+
+        let mut cached = 0;
+        for (loc, tile) in inst.splitters() {
+            cached += 1;
+            for facing in tile.split_facings() {
+                if let Some((location, _)) = inst.grid.cardinal_neighbor(&loc, facing) {
+                    inst.trace_segment_with(Particle { location, facing }, |_| cached += 1);
+                }
+            }
+        }
+        for p in starts.iter() {
+            inst.trace_segment_with(*p, |_| cached += 1);
+        }
+
+        (uncached, cached)
+    }
+
     #[test]
     #[ignore]
     fn full_dataset() {
@@ -291,17 +585,63 @@ mod tests {
 
     #[test]
     fn example() {
-        let input = r#".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|...."#;
-        let solution = TheFloorWillBeLava::solve(input).unwrap();
+        let solution = TheFloorWillBeLava::solve(EXAMPLE).unwrap();
         assert_eq!(solution, Solution::new(46, 51));
     }
+
+    #[test]
+    fn cached_propagation() {
+        let inst = TheFloorWillBeLava::instance(EXAMPLE).unwrap();
+        assert_eq!(inst.propagate_all(), 51);
+
+        let cache = inst.propagate_cache();
+        for p in inst.starting_particles() {
+            assert_eq!(inst.propagate_cached(p, &cache), inst.propagate(p));
+        }
+    }
+
+    #[test]
+    fn cached_visits_fewer_tiles() {
+        let inst = TheFloorWillBeLava::instance(EXAMPLE).unwrap();
+        let (uncached, cached) = tile_visits(&inst);
+        assert!(cached > 0);
+        assert!(cached < uncached, "{} >= {}", cached, uncached);
+    }
+
+    #[test]
+    fn energized_set_iter() {
+        let mut set = EnergizedSet::new(4);
+        set.add(&Location::new(3, 0));
+        set.add(&Location::new(0, 127));
+        set.add(&Location::new(1, 5));
+        set.add(&Location::new(1, 5));
+
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![
+                Location::new(0, 127),
+                Location::new(1, 5),
+                Location::new(3, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn trace() {
+        let inst = TheFloorWillBeLava::instance(EXAMPLE).unwrap();
+        let trace = inst.propagate_trace(Particle::default());
+
+        assert_eq!(trace[0], Location::new(0, 0));
+        assert_eq!(trace[1], Location::new(0, 1));
+        assert_eq!(trace.len(), 46);
+
+        let energized = inst.propagate(Particle::default());
+        assert!(trace.iter().all(|loc| energized.contains(loc)));
+    }
+
+    #[test]
+    fn invalid_tile() {
+        assert!(Tile::try_from('X').is_err());
+        assert!(TheFloorWillBeLava::instance(".|.\n.X.\n...").is_err());
+    }
 }
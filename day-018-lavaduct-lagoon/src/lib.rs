@@ -96,10 +96,31 @@ impl LavaductLagoon {
 
     pub fn dig<'a, I: Iterator<Item = &'a Instruction>>(&self, iter: I) -> i64 {
         let (perimeter, polygon) = self.make_veritices(iter);
-        let area = polygon.shoelace_area();
+        perimeter + Self::enclosed(perimeter, &polygon)
+    }
+
+    /// The number of cells on the edge of the lagoon, using either the plain
+    /// or hex-encoded instructions.
+    pub fn perimeter(&self, use_hex: bool) -> i64 {
+        self.selected(use_hex).map(|inst| inst.amount).sum()
+    }
+
+    /// The number of cells strictly inside the edge of the lagoon, using either
+    /// the plain or hex-encoded instructions.
+    pub fn interior(&self, use_hex: bool) -> i64 {
+        let (perimeter, polygon) = self.make_veritices(self.selected(use_hex));
+        Self::enclosed(perimeter, &polygon)
+    }
+
+    fn selected(&self, use_hex: bool) -> impl Iterator<Item = &Instruction> {
+        self.instructions
+            .iter()
+            .map(move |(plain, hex)| if use_hex { hex } else { plain })
+    }
 
-        let inside = area - perimeter / 2 + 1;
-        perimeter + inside
+    // Pick's theorem, given the area from the shoelace formula
+    fn enclosed(perimeter: i64, polygon: &Polygon<i64>) -> i64 {
+        polygon.shoelace_area() - perimeter / 2 + 1
     }
 }
 
@@ -163,4 +184,26 @@ U 2 (#7a21e3)";
         let solution = LavaductLagoon::solve(input).unwrap();
         assert_eq!(solution, Solution::new(62, 952408144115));
     }
+
+    #[test]
+    fn perimeter_and_interior() {
+        let input = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+        let inst = LavaductLagoon::instance(input).unwrap();
+        assert_eq!(inst.perimeter(false), 38);
+        assert_eq!(inst.interior(false), 24);
+        assert_eq!(inst.perimeter(true) + inst.interior(true), 952408144115);
+    }
 }
@@ -191,6 +191,10 @@ pub struct Part {
 }
 
 impl Part {
+    pub fn new(x: i64, m: i64, a: i64, s: i64) -> Self {
+        Self { x, m, a, s }
+    }
+
     pub fn get(&self, key: &Key) -> i64 {
         match key {
             Key::X => self.x,
@@ -228,7 +232,7 @@ fn parse_part(input: &str) -> IResult<&str, Part> {
             tuple((parse_x, parse_m, parse_a, parse_s)),
             complete::char('}'),
         ),
-        |(x, m, a, s)| Part { x, m, a, s },
+        |(x, m, a, s)| Part::new(x, m, a, s),
     )(input)
 }
 
@@ -248,6 +252,13 @@ pub struct IntervalSet {
     s: Interval<i64>,
 }
 
+impl IntervalSet {
+    /// The number of distinct parts contained in this set.
+    pub fn volume(&self) -> i64 {
+        self.x.width() * self.m.width() * self.a.width() * self.s.width()
+    }
+}
+
 impl Default for IntervalSet {
     fn default() -> Self {
         Self {
@@ -266,36 +277,62 @@ pub struct Aplenty {
 }
 
 impl Aplenty {
-    pub fn sum_accepted(&self) -> i64 {
-        let in_workflow = xxh3_64(b"in");
-        let mut total = 0;
-        for part in self.parts.iter() {
-            let mut workflow = in_workflow;
-            loop {
-                let decision = self.workflows.get(&workflow).unwrap().process(part);
-                match decision {
-                    Decision::Accept => {
-                        total += part.total_rating();
-                        break;
-                    }
-                    Decision::Reject => {
-                        break;
-                    }
-                    Decision::Workflow(next_workflow) => {
-                        workflow = next_workflow;
-                    }
+    /// Run the given part through the workflows, starting at `in`, until it is
+    /// either accepted or rejected.
+    pub fn classify(&self, part: &Part) -> Decision {
+        let mut workflow = xxh3_64(b"in");
+        loop {
+            match self.workflows.get(&workflow).unwrap().process(part) {
+                Decision::Workflow(next_workflow) => {
+                    workflow = next_workflow;
                 }
+                decision => return decision,
             }
         }
-        total
+    }
+
+    pub fn sum_accepted(&self) -> i64 {
+        self.parts
+            .iter()
+            .filter(|part| self.classify(part) == Decision::Accept)
+            .map(|part| part.total_rating())
+            .sum()
     }
 
     pub fn combo_accepted(&self) -> i64 {
+        self.accepted_regions()
+            .iter()
+            .map(IntervalSet::volume)
+            .sum()
+    }
+
+    /// The total number of the possible parts that are rejected.
+    pub fn rejected_volume(&self) -> i64 {
+        self.rejected_regions()
+            .iter()
+            .map(IntervalSet::volume)
+            .sum()
+    }
+
+    /// The disjoint regions of the space of possible parts that are accepted.
+    pub fn accepted_regions(&self) -> Vec<IntervalSet> {
+        self.partition_regions().0
+    }
+
+    /// The disjoint regions of the space of possible parts that are rejected.
+    pub fn rejected_regions(&self) -> Vec<IntervalSet> {
+        self.partition_regions().1
+    }
+
+    /// Split the space of possible parts into the regions that are accepted and
+    /// the regions that are rejected.
+    fn partition_regions(&self) -> (Vec<IntervalSet>, Vec<IntervalSet>) {
         let in_workflow = xxh3_64(b"in");
         let mut intervals: Vec<(IntervalSet, Decision, usize)> =
             vec![(IntervalSet::default(), Decision::Workflow(in_workflow), 0)];
 
         let mut accepted: Vec<IntervalSet> = Vec::default();
+        let mut rejected: Vec<IntervalSet> = Vec::default();
 
         while let Some((interval_set, old_decision, rule_idx)) = intervals.pop() {
             match old_decision {
@@ -465,17 +502,18 @@ impl Aplenty {
                                 }
                             },
                         },
-                        Rule::AlwaysReject => { /* do nothing */ }
+                        Rule::AlwaysReject => {
+                            rejected.push(interval_set);
+                        }
                     }
                 }
-                _ => {}
+                Decision::Reject => {
+                    rejected.push(interval_set);
+                }
             }
         }
 
-        accepted
-            .iter()
-            .map(|iset| iset.x.width() * iset.m.width() * iset.a.width() * iset.s.width())
-            .sum()
+        (accepted, rejected)
     }
 }
 
@@ -543,9 +581,72 @@ hdj{m>838:A,pv}
         assert_eq!(solution, Solution::new(19114, 167409079868000));
     }
 
+    #[test]
+    fn classify() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}";
+        let inst = Aplenty::from_str(input).unwrap();
+
+        let part = Part::new(1, 1, 1, 1);
+        assert_eq!(inst.classify(&part), Decision::Accept);
+
+        // in -> qqz -> qs -> lnx -> A
+        let part = Part::new(787, 2655, 1222, 2876);
+        assert_eq!(inst.classify(&part), Decision::Accept);
+
+        // in -> px -> rfg -> gd -> R
+        let part = Part::new(1679, 44, 2067, 496);
+        assert_eq!(inst.classify(&part), Decision::Reject);
+    }
+
+    #[test]
+    fn part() {
+        let part = Part::new(787, 2655, 1222, 2876);
+        assert_eq!(part.get(&Key::X), 787);
+        assert_eq!(part.get(&Key::M), 2655);
+        assert_eq!(part.get(&Key::A), 1222);
+        assert_eq!(part.get(&Key::S), 2876);
+        assert_eq!(part.total_rating(), 7540);
+        assert_eq!(parse_part("{x=787,m=2655,a=1222,s=2876}").unwrap().1, part);
+    }
+
     #[test]
     fn parsing() {
         let (_, _r) = parse_rule("a<2006:qkq").unwrap();
         let (_, _w) = parse_workflow("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
     }
+
+    #[test]
+    fn rejected_volume() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}";
+        let inst = Aplenty::from_str(input).unwrap();
+        assert_eq!(
+            inst.combo_accepted() + inst.rejected_volume(),
+            4000_i64.pow(4)
+        );
+        assert!(!inst.rejected_regions().is_empty());
+    }
 }
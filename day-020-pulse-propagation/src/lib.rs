@@ -42,6 +42,35 @@ impl CommMod {
             Self::Broadcast { destinations } => destinations,
         }
     }
+
+    /// Handle a pulse sent from `origin`, returning the pulse this module then
+    /// sends to all of its destinations, if any.
+    pub fn receive(&mut self, origin: u64, pulse: Pulse) -> Option<Pulse> {
+        match self {
+            Self::FlipFlop { state, .. } => {
+                if pulse == Pulse::High {
+                    return None;
+                }
+
+                *state = !*state;
+                if *state {
+                    Some(Pulse::High)
+                } else {
+                    Some(Pulse::Low)
+                }
+            }
+            Self::Conjunction { inputs, .. } => {
+                inputs.insert(origin, pulse);
+
+                if inputs.values().all(|v| *v == Pulse::High) {
+                    Some(Pulse::Low)
+                } else {
+                    Some(Pulse::High)
+                }
+            }
+            Self::Broadcast { .. } => Some(pulse),
+        }
+    }
 }
 
 fn parse_destinations(input: &str) -> IResult<&str, Vec<u64>> {
@@ -124,14 +153,57 @@ fn parse_comm_mods(input: &str) -> IResult<&str, FxHashMap<u64, CommMod>> {
 #[derive(Debug, Clone)]
 pub struct PulsePropagation {
     mods: FxHashMap<u64, CommMod>,
+    initial: FxHashMap<u64, CommMod>,
     cycle_conjunction_key: u64,
 }
 
 impl PulsePropagation {
+    /// The current on/off state of every flip-flop. See `conjunction_memories`
+    /// for the state of the conjunctions.
+    pub fn module_states(&self) -> FxHashMap<u64, bool> {
+        self.mods
+            .iter()
+            .filter_map(|(k, m)| match m {
+                CommMod::FlipFlop { state, .. } => Some((*k, *state)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The most recent pulse every conjunction remembers from each of its
+    /// inputs, keyed on the conjunction.
+    pub fn conjunction_memories(&self) -> FxHashMap<u64, FxHashMap<u64, Pulse>> {
+        self.mods
+            .iter()
+            .filter_map(|(k, m)| match m {
+                CommMod::Conjunction { inputs, .. } => Some((*k, inputs.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Push the button once, propagating all resulting pulses through the
+    /// current module states.
+    pub fn press(&mut self) {
+        let broadcaster = xxh3_64(b"broadcaster");
+        let button = xxh3_64(b"button");
+
+        let mut pulses = VecDeque::default();
+        pulses.push_back((button, broadcaster, Pulse::Low));
+        while let Some((origin, dest, pulse)) = pulses.pop_front() {
+            Self::deliver(&mut self.mods, &mut pulses, origin, dest, pulse);
+        }
+    }
+
+    /// Restore every module to the state it was in when parsed.
+    pub fn reset(&mut self) {
+        self.mods.clone_from(&self.initial);
+    }
+
     pub fn push_button(&self) -> usize {
         let broadcaster = xxh3_64(b"broadcaster");
         let button = xxh3_64(b"button");
-        let mut mods = self.mods.clone();
+        let mut mods = self.initial.clone();
         let mut low_pulses = 0;
         let mut high_pulses = 0;
 
@@ -139,72 +211,47 @@ impl PulsePropagation {
 
         for _ in 0..1000 {
             pulses.push_back((button, broadcaster, Pulse::Low));
-            low_pulses += 1;
             while let Some((origin, dest, pulse)) = pulses.pop_front() {
-                if let Some(cur_mod) = mods.get_mut(&dest) {
-                    match cur_mod {
-                        CommMod::FlipFlop {
-                            state,
-                            destinations,
-                        } => {
-                            if pulse == Pulse::High {
-                                continue;
-                            }
-
-                            *state = !*state;
-                            let next_pulse = if *state {
-                                high_pulses += destinations.len();
-                                Pulse::High
-                            } else {
-                                low_pulses += destinations.len();
-                                Pulse::Low
-                            };
-
-                            pulses.extend(
-                                destinations.iter().copied().map(|d| (dest, d, next_pulse)),
-                            );
-                        }
-                        CommMod::Conjunction {
-                            inputs,
-                            destinations,
-                        } => {
-                            inputs.insert(origin, pulse);
-
-                            let next_pulse = if inputs.values().all(|v| *v == Pulse::High) {
-                                low_pulses += destinations.len();
-                                Pulse::Low
-                            } else {
-                                high_pulses += destinations.len();
-                                Pulse::High
-                            };
-
-                            pulses.extend(
-                                destinations.iter().copied().map(|d| (dest, d, next_pulse)),
-                            );
-                        }
-                        CommMod::Broadcast { destinations } => {
-                            if pulse == Pulse::High {
-                                high_pulses += destinations.len();
-                            } else {
-                                low_pulses += destinations.len();
-                            }
-                            pulses.extend(destinations.iter().copied().map(|d| (dest, d, pulse)));
-                        }
-                    }
+                match pulse {
+                    Pulse::High => high_pulses += 1,
+                    Pulse::Low => low_pulses += 1,
                 }
+
+                Self::deliver(&mut mods, &mut pulses, origin, dest, pulse);
             }
         }
 
         low_pulses * high_pulses
     }
 
+    /// Every `(origin, destination, pulse)` sent over the given number of
+    /// button presses, in the order they were sent.
+    pub fn record_pulses(&self, presses: usize) -> Vec<(u64, u64, Pulse)> {
+        let broadcaster = xxh3_64(b"broadcaster");
+        let button = xxh3_64(b"button");
+        let mut mods = self.initial.clone();
+        let mut history = Vec::default();
+
+        let mut pulses = VecDeque::default();
+
+        for _ in 0..presses {
+            pulses.push_back((button, broadcaster, Pulse::Low));
+            while let Some((origin, dest, pulse)) = pulses.pop_front() {
+                history.push((origin, dest, pulse));
+                Self::deliver(&mut mods, &mut pulses, origin, dest, pulse);
+            }
+        }
+
+        history
+    }
+
     pub fn push_button_until(&self) -> usize {
         let rx = xxh3_64(b"rx");
         let broadcaster = xxh3_64(b"broadcaster");
         let button = xxh3_64(b"button");
-        let mut mods = self.mods.clone();
+        let mut mods = self.initial.clone();
 
-        let rx_conjunction = self.mods.get(&self.cycle_conjunction_key).unwrap();
+        let rx_conjunction = self.initial.get(&self.cycle_conjunction_key).unwrap();
         let mut cycle_markers = FxHashMap::default();
         match rx_conjunction {
             CommMod::Conjunction { inputs, .. } => {
@@ -224,61 +271,47 @@ impl PulsePropagation {
                 if dest == rx && pulse == Pulse::Low {
                     return count;
                 }
-                if let Some(cur_mod) = mods.get_mut(&dest) {
-                    match cur_mod {
-                        CommMod::FlipFlop {
-                            state,
-                            destinations,
-                        } => {
-                            if pulse == Pulse::High {
-                                continue;
-                            }
-
-                            *state = !*state;
-                            let next_pulse = if *state { Pulse::High } else { Pulse::Low };
-
-                            pulses.extend(
-                                destinations.iter().copied().map(|d| (dest, d, next_pulse)),
-                            );
-                        }
-                        CommMod::Conjunction {
-                            inputs,
-                            destinations,
-                        } => {
-                            inputs.insert(origin, pulse);
-
-                            if dest == self.cycle_conjunction_key && pulse == Pulse::High {
-                                let e = cycle_markers.entry(origin).or_default();
-                                e.push(count + 1);
-
-                                // if all the markers have at least one pulse,
-                                // calculate the cycle. We're going to assume
-                                // that the cycles are prime and they all start
-                                // at zero, but this could be wrong
-                                if cycle_markers.values().all(|v| !v.is_empty()) {
-                                    return cycle_markers.values().map(|v| v[0]).product::<usize>();
-                                }
-                            }
-
-                            let next_pulse = if inputs.values().all(|v| *v == Pulse::High) {
-                                Pulse::Low
-                            } else {
-                                Pulse::High
-                            };
-
-                            pulses.extend(
-                                destinations.iter().copied().map(|d| (dest, d, next_pulse)),
-                            );
-                        }
-                        CommMod::Broadcast { destinations } => {
-                            pulses.extend(destinations.iter().copied().map(|d| (dest, d, pulse)));
-                        }
+
+                if dest == self.cycle_conjunction_key && pulse == Pulse::High {
+                    let e = cycle_markers.entry(origin).or_default();
+                    e.push(count + 1);
+
+                    // if all the markers have at least one pulse, calculate
+                    // the cycle. We're going to assume that the cycles are
+                    // prime and they all start at zero, but this could be
+                    // wrong
+                    if cycle_markers.values().all(|v| !v.is_empty()) {
+                        return cycle_markers.values().map(|v| v[0]).product::<usize>();
                     }
                 }
+
+                Self::deliver(&mut mods, &mut pulses, origin, dest, pulse);
             }
             count += 1;
         }
     }
+
+    /// Deliver the pulse to its destination module, if there is one, queueing
+    /// any pulses the module sends in response.
+    fn deliver(
+        mods: &mut FxHashMap<u64, CommMod>,
+        pulses: &mut VecDeque<(u64, u64, Pulse)>,
+        origin: u64,
+        dest: u64,
+        pulse: Pulse,
+    ) {
+        if let Some(cur_mod) = mods.get_mut(&dest) {
+            if let Some(next_pulse) = cur_mod.receive(origin, pulse) {
+                pulses.extend(
+                    cur_mod
+                        .destinations()
+                        .iter()
+                        .copied()
+                        .map(|d| (dest, d, next_pulse)),
+                );
+            }
+        }
+    }
 }
 
 impl FromStr for PulsePropagation {
@@ -307,6 +340,7 @@ impl FromStr for PulsePropagation {
         }
 
         Ok(Self {
+            initial: mods.clone(),
             mods,
             cycle_conjunction_key,
         })
@@ -355,4 +389,75 @@ mod tests {
         let mut inst = PulsePropagation::instance(input).unwrap();
         assert_eq!(inst.part_one().unwrap(), 32000000);
     }
+
+    #[test]
+    fn record_pulses() {
+        let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+        let inst = PulsePropagation::instance(input).unwrap();
+        let history = inst.record_pulses(1);
+        assert_eq!(history.len(), 12);
+        assert_eq!(
+            history[0],
+            (xxh3_64(b"button"), xxh3_64(b"broadcaster"), Pulse::Low)
+        );
+        assert_eq!(
+            history.iter().filter(|(_, _, p)| *p == Pulse::High).count(),
+            4
+        );
+    }
+
+    #[test]
+    fn module_states() {
+        let input = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+        let mut inst = PulsePropagation::instance(input).unwrap();
+        let initial = inst.module_states();
+        let a = xxh3_64(b"a");
+        let b = xxh3_64(b"b");
+        let c = xxh3_64(b"c");
+        assert!(!initial[&a] && !initial[&b] && !initial[&c]);
+
+        // after one press, this example's flip-flops all end up off again
+        inst.press();
+        assert_eq!(inst.module_states(), initial);
+
+        let input = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+        let mut inst = PulsePropagation::instance(input).unwrap();
+        let initial = inst.module_states();
+        let initial_memories = inst.conjunction_memories();
+        assert_eq!(initial.len(), 2);
+        assert!(!initial[&a] && !initial[&b]);
+
+        let inv = xxh3_64(b"inv");
+        let con = xxh3_64(b"con");
+        assert_eq!(initial_memories.len(), 2);
+        assert!(initial_memories
+            .values()
+            .flat_map(|m| m.values())
+            .all(|p| *p == Pulse::Low));
+
+        inst.press();
+        let pressed = inst.module_states();
+        assert!(pressed[&a] && pressed[&b]);
+
+        let memories = inst.conjunction_memories();
+        assert_eq!(memories[&inv][&a], Pulse::High);
+        assert_eq!(memories[&con][&a], Pulse::High);
+        assert_eq!(memories[&con][&b], Pulse::High);
+
+        inst.reset();
+        assert_eq!(inst.module_states(), initial);
+        assert_eq!(inst.conjunction_memories(), initial_memories);
+    }
 }
@@ -1,5 +1,6 @@
 use std::{collections::VecDeque, str::FromStr};
 
+use anyhow::anyhow;
 use aoc_plumbing::Problem;
 use aoc_std::{
     collections::Grid,
@@ -13,13 +14,26 @@ pub enum Tile {
     Rock,
 }
 
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    /// The start is also a garden plot.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' | 'S' => Ok(Self::Garden),
+            '#' => Ok(Self::Rock),
+            _ => Err(anyhow!("Invalid tile: {}", value)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct StepCounter {
+pub struct StepCounterGen<const STEPS: usize> {
     grid: Grid<Tile>,
     start: Location,
 }
 
-impl StepCounter {
+impl<const STEPS: usize> StepCounterGen<STEPS> {
     pub fn bfs(&self, steps: usize) -> usize {
         let parity = steps % 2 == 0;
         let mut seen = vec![vec![false; self.grid.width()]; self.grid.height()];
@@ -245,7 +259,7 @@ impl StepCounter {
     }
 }
 
-impl FromStr for StepCounter {
+impl<const STEPS: usize> FromStr for StepCounterGen<STEPS> {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -257,19 +271,16 @@ impl FromStr for StepCounter {
             .map(|(row, line)| {
                 line.chars()
                     .enumerate()
-                    .map(|(col, ch)| match ch {
-                        '.' => Tile::Garden,
-                        '#' => Tile::Rock,
-                        'S' => {
+                    .map(|(col, ch)| {
+                        if ch == 'S' {
                             start.row = row;
                             start.col = col;
-                            Tile::Garden
                         }
-                        _ => unreachable!("Unexpected character"),
+                        Tile::try_from(ch)
                     })
-                    .collect::<Vec<_>>()
+                    .collect::<Result<Vec<_>, _>>()
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         let grid = Grid::new(locations);
 
@@ -277,7 +288,7 @@ impl FromStr for StepCounter {
     }
 }
 
-impl Problem for StepCounter {
+impl<const STEPS: usize> Problem for StepCounterGen<STEPS> {
     const DAY: usize = 21;
     const TITLE: &'static str = "step counter";
     const README: &'static str = include_str!("../README.md");
@@ -287,7 +298,7 @@ impl Problem for StepCounter {
     type P2 = u64;
 
     fn part_one(&mut self) -> Result<Self::P1, Self::ProblemError> {
-        Ok(self.bfs(64))
+        Ok(self.bfs(STEPS))
     }
 
     fn part_two(&mut self) -> Result<Self::P2, Self::ProblemError> {
@@ -295,6 +306,8 @@ impl Problem for StepCounter {
     }
 }
 
+pub type StepCounter = StepCounterGen<64>;
+
 #[cfg(test)]
 mod tests {
     use aoc_plumbing::Solution;
@@ -325,5 +338,14 @@ mod tests {
 ...........";
         let mut inst = StepCounter::instance(input).unwrap();
         assert_eq!(inst.part_one().unwrap(), 42);
+
+        let mut inst = StepCounterGen::<6>::instance(input).unwrap();
+        assert_eq!(inst.part_one().unwrap(), 16);
+    }
+
+    #[test]
+    fn invalid_tile() {
+        assert!(Tile::try_from('X').is_err());
+        assert!(StepCounter::instance("...\n.SX\n...").is_err());
     }
 }
@@ -85,10 +85,32 @@ impl PartialOrd for StableEntry {
 pub struct SandSlabs {
     p1: usize,
     p2: usize,
+    above: Vec<Vec<usize>>,
+    below: Vec<Vec<usize>>,
+    heights: Vec<i16>,
 }
 
 impl SandSlabs {
-    pub fn settle(bricks: Vec<Brick>) -> (usize, usize) {
+    /// The number of other bricks that would fall if the brick with the given
+    /// index were disintegrated.
+    ///
+    /// Bricks are indexed in the order they were settled, which is ascending
+    /// order of their starting heights.
+    ///
+    /// Panics if `brick` is out of bounds.
+    pub fn chain_reaction(&self, brick: usize) -> usize {
+        Self::search(brick, &self.above, &self.below, self.above.len())
+    }
+
+    /// The z coordinate each brick came to rest at, indexed the same way as
+    /// `chain_reaction`.
+    pub fn settled_heights(&self) -> Vec<i16> {
+        self.heights.clone()
+    }
+
+    /// Settle the bricks in the order given, which is expected to be ascending
+    /// order of starting height.
+    pub fn settle(bricks: Vec<Brick>) -> Self {
         let num_bricks = bricks.len();
         let mut bricks = bricks;
         let mut max_x = 0;
@@ -165,7 +187,13 @@ impl SandSlabs {
             .map(|i| Self::search(i, &above, &below, num_bricks))
             .sum();
 
-        (p1, p2)
+        Self {
+            p1,
+            p2,
+            above,
+            below,
+            heights: bricks.iter().map(|b| b.cube.start.z).collect(),
+        }
     }
 
     pub fn search(
@@ -206,11 +234,14 @@ impl FromStr for SandSlabs {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, mut bricks) = parse_bricks(s).map_err(|e| e.to_owned())?;
-        bricks.sort_by(|a, b| a.cube.start.z.cmp(&b.cube.start.z));
+        let (_, bricks) = parse_bricks(s).map_err(|e| e.to_owned())?;
+
+        // bricks starting at the same height are settled in the order they
+        // appear in the input, so the brick indices are always the same
+        let mut bricks = bricks.into_iter().enumerate().collect::<Vec<_>>();
+        bricks.sort_unstable_by_key(|(idx, b)| (b.cube.start.z, *idx));
 
-        let (p1, p2) = Self::settle(bricks);
-        Ok(Self { p1, p2 })
+        Ok(Self::settle(bricks.into_iter().map(|(_, b)| b).collect()))
     }
 }
 
@@ -258,4 +289,43 @@ mod tests {
         let solution = SandSlabs::solve(input).unwrap();
         assert_eq!(solution, Solution::new(5, 7));
     }
+
+    #[test]
+    fn chain_reaction() {
+        let input = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+        let inst = SandSlabs::instance(input).unwrap();
+        assert_eq!(inst.chain_reaction(0), 6);
+        assert_eq!(inst.chain_reaction(5), 1);
+
+        // these can all be safely disintegrated
+        for brick in [1, 2, 3, 4, 6] {
+            assert_eq!(inst.chain_reaction(brick), 0);
+        }
+    }
+
+    #[test]
+    fn settled_heights() {
+        let input = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+        let heights = SandSlabs::instance(input).unwrap().settled_heights();
+        assert_eq!(heights, vec![1, 2, 2, 3, 3, 4, 5]);
+
+        for _ in 0..5 {
+            assert_eq!(
+                SandSlabs::instance(input).unwrap().settled_heights(),
+                heights
+            );
+        }
+    }
 }
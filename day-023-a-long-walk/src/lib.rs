@@ -16,6 +16,22 @@ pub enum Tile {
     Wall,
 }
 
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            '>' => Ok(Self::SlopeEast),
+            '<' => Ok(Self::SlopeWest),
+            '^' => Ok(Self::SlopeNorth),
+            'v' => Ok(Self::SlopeSouth),
+            _ => Err(anyhow!("Invalid tile: {}", value)),
+        }
+    }
+}
+
 impl Tile {
     pub fn permitted(&self, dir: &Cardinal) -> bool {
         match self {
@@ -47,6 +63,10 @@ impl Node {
             best: 0,
         }
     }
+
+    pub fn location(&self) -> Location {
+        self.location
+    }
 }
 
 // this is maybe too tuned to the input shape everyone was given, but a way to
@@ -84,9 +104,34 @@ impl LayerSet {
 pub struct ALongWalkGen<const N: usize> {
     p1: usize,
     p2: usize,
+    graph: Vec<Node>,
 }
 
 impl<const N: usize> ALongWalkGen<N> {
+    /// The contracted graph (without slopes) that was searched, where node 0
+    /// is the start and node 1 is the end.
+    pub fn nodes(&self) -> &[Node] {
+        &self.graph
+    }
+
+    /// Get the grid location of the node with the given search index.
+    ///
+    /// Panics if `idx` is out of bounds.
+    pub fn node_location(&self, idx: usize) -> Location {
+        self.graph[idx].location
+    }
+
+    /// Solve part two again, diving `depth` levels into the graph to find
+    /// the starting points for the parallel search instead of `N`.
+    pub fn solve_with_depth(&self, depth: usize) -> usize {
+        let mut layer_set = LayerSet::default();
+        for node in self.graph.iter() {
+            layer_set.increment(node.layer);
+        }
+
+        Self::longest_distance_with_depth(&self.graph, layer_set, false, depth)
+    }
+
     pub fn make_base_graph(grid: &Grid<Tile>) -> Vec<Node> {
         let mut graph: Vec<Node> = Vec::default();
 
@@ -308,7 +353,16 @@ impl<const N: usize> ALongWalkGen<N> {
         usize::MAX
     }
 
-    pub fn longest_distance(graph: &[Node], mut layer_set: LayerSet, sloped: bool) -> usize {
+    pub fn longest_distance(graph: &[Node], layer_set: LayerSet, sloped: bool) -> usize {
+        Self::longest_distance_with_depth(graph, layer_set, sloped, N)
+    }
+
+    pub fn longest_distance_with_depth(
+        graph: &[Node],
+        mut layer_set: LayerSet,
+        sloped: bool,
+        depth: usize,
+    ) -> usize {
         // we're going to use the layer set to eliminate situations where we are
         // forced to descend towards the end because otherwise we would not be
         // able to cross a particular layer again
@@ -347,32 +401,37 @@ impl<const N: usize> ALongWalkGen<N> {
             layer_set,
             theoretical_best,
         ));
-        for _depth in 2..N {
-            next.extend(
-                starting_points
-                    .drain(..)
-                    .flat_map(|(idx, dist, seen, mut ls, best)| {
-                        ls.decrement(graph[idx].layer);
-                        let best_remaining = if best > 0 {
-                            best - graph[idx].best
-                        } else {
-                            best
-                        };
-                        graph[idx]
-                            .neighbors
-                            .iter()
-                            .filter(move |(fidx, _)| 1_u64 << fidx & seen == 0)
-                            .map(move |(fidx, fdist)| {
-                                (
-                                    *fidx,
-                                    dist + fdist,
-                                    seen | 1_u64 << fidx,
-                                    ls,
-                                    best_remaining,
-                                )
-                            })
-                    }),
-            );
+        for _depth in 2..depth {
+            for (idx, dist, seen, mut ls, best) in starting_points.drain(..) {
+                // with enough depth we can reach the end before we're done
+                // diving, and we don't want to lose those paths
+                if idx == end {
+                    next.push((idx, dist, seen, ls, best));
+                    continue;
+                }
+
+                ls.decrement(graph[idx].layer);
+                let best_remaining = if best > 0 {
+                    best - graph[idx].best
+                } else {
+                    best
+                };
+                next.extend(
+                    graph[idx]
+                        .neighbors
+                        .iter()
+                        .filter(|(fidx, _)| 1_u64 << fidx & seen == 0)
+                        .map(|(fidx, fdist)| {
+                            (
+                                *fidx,
+                                dist + fdist,
+                                seen | 1_u64 << fidx,
+                                ls,
+                                best_remaining,
+                            )
+                        }),
+                );
+            }
             std::mem::swap(&mut starting_points, &mut next);
         }
 
@@ -417,6 +476,28 @@ impl<const N: usize> ALongWalkGen<N> {
         }
     }
 
+    /// The longest path from node 0 to `end` found by a plain dfs, without
+    /// any of the pruning. This is only practical for small graphs, and is
+    /// intended for checking the results of the pruned search.
+    pub fn longest_bruteforce(graph: &[Node], end: usize) -> usize {
+        fn recur(idx: usize, cost: usize, end: usize, graph: &[Node], seen: u64) -> usize {
+            if idx == end {
+                return cost;
+            }
+
+            let next_seen = seen | 1_u64 << idx;
+            graph[idx]
+                .neighbors
+                .iter()
+                .filter(|(n, _)| (1_u64 << n) & next_seen == 0)
+                .map(|(n, dist)| recur(*n, cost + dist, end, graph, next_seen))
+                .max()
+                .unwrap_or_default()
+        }
+
+        recur(0, 0, end, graph, 0)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn longest_recur_sloped(
         start: usize,
@@ -549,18 +630,10 @@ impl<const N: usize> FromStr for ALongWalkGen<N> {
             .lines()
             .map(|line| {
                 line.chars()
-                    .map(|ch| match ch {
-                        '.' => Tile::Empty,
-                        '#' => Tile::Wall,
-                        '>' => Tile::SlopeEast,
-                        '<' => Tile::SlopeWest,
-                        '^' => Tile::SlopeNorth,
-                        'v' => Tile::SlopeSouth,
-                        _ => unreachable!("Unexpected character"),
-                    })
-                    .collect::<Vec<_>>()
+                    .map(Tile::try_from)
+                    .collect::<Result<Vec<_>, _>>()
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         let grid = Arc::new(Grid::new(locations));
 
@@ -581,17 +654,17 @@ impl<const N: usize> FromStr for ALongWalkGen<N> {
         let p2_handle = thread::spawn(move || {
             let mut g = Self::populate_graph_without_slopes(&p2_graph, &p2_grid);
             let layer_set = Self::compute_layer_set_and_update_nodes(1, &mut g);
-            Self::longest_distance(&g, layer_set, false)
+            (Self::longest_distance(&g, layer_set, false), g)
         });
 
         let p1 = p1_handle
             .join()
             .map_err(|e| anyhow!("failed to solve p1: {:?}", e))?;
-        let p2 = p2_handle
+        let (p2, graph) = p2_handle
             .join()
             .map_err(|e| anyhow!("failed to solve p1: {:?}", e))?;
 
-        Ok(Self { p1, p2 })
+        Ok(Self { p1, p2, graph })
     }
 }
 
@@ -657,4 +730,107 @@ mod tests {
         let solution = ALongWalkGen::<5>::solve(input).unwrap();
         assert_eq!(solution, Solution::new(94, 154));
     }
+
+    #[test]
+    fn node_locations() {
+        let input = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+        let inst = ALongWalkGen::<5>::instance(input).unwrap();
+        assert_eq!(inst.node_location(0), Location::new(0, 1));
+        assert_eq!(inst.node_location(1), Location::new(22, 21));
+        assert_eq!(inst.nodes().len(), 9);
+        assert!(inst
+            .nodes()
+            .iter()
+            .enumerate()
+            .all(|(idx, n)| inst.node_location(idx) == n.location()));
+    }
+
+    #[test]
+    fn invalid_tile() {
+        assert!(Tile::try_from('X').is_err());
+        assert!(ALongWalk::instance("#.#\n#X#\n#.#").is_err());
+    }
+
+    #[test]
+    fn solve_with_depth() {
+        let input = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+        let inst = ALongWalkGen::<5>::instance(input).unwrap();
+        assert_eq!(inst.solve_with_depth(3), 154);
+        assert_eq!(inst.solve_with_depth(8), 154);
+    }
+
+    #[test]
+    fn longest_bruteforce() {
+        let input = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+        let mut inst = ALongWalkGen::<5>::instance(input).unwrap();
+        let expected = ALongWalkGen::<5>::longest_bruteforce(inst.nodes(), 1);
+        assert_eq!(expected, 154);
+        assert_eq!(inst.part_two().unwrap(), expected);
+    }
 }
@@ -6,10 +6,10 @@ use itertools::Itertools;
 use nalgebra::{matrix, vector, Vector3};
 use nom::{
     bytes::complete::tag,
-    character::complete::{self, newline, space1},
+    character::complete::{self, newline, space0, space1},
     combinator,
     multi::separated_list1,
-    sequence::{separated_pair, terminated, tuple},
+    sequence::{preceded, separated_pair, terminated, tuple},
     IResult,
 };
 
@@ -20,13 +20,24 @@ pub struct Hail {
 }
 
 impl Hail {
+    pub fn position(&self) -> &Point3D<i64> {
+        &self.position
+    }
+
+    pub fn velocity(&self) -> &Point3D<i64> {
+        &self.velocity
+    }
+
     pub fn intersects_xy(&self, other: &Self) -> bool {
         self.velocity.x * other.velocity.y - other.velocity.x * self.velocity.y != 0
     }
 
     // | vx1, -vx2 | | s | = | x2 - x1 |
     // | vy1, -vy2 | | t |   | y2 - y1 |
-    pub fn intersect_location_xy(&self, other: &Self) -> Option<(f64, f64)> {
+    /// The times `(s, t)` at which this and the other hailstone respectively
+    /// reach the point where their paths cross in xy, provided both are in
+    /// the future.
+    pub fn intersect_times(&self, other: &Self) -> Option<(f64, f64)> {
         let d = other.velocity.x * self.velocity.y - self.velocity.x * other.velocity.y;
 
         if d != 0 {
@@ -49,24 +60,35 @@ impl Hail {
                 return None;
             }
 
-            let x = x1 + s * vx1;
-            let y = y1 + s * vy1;
-
-            Some((x, y))
+            Some((s, t))
         } else {
             None
         }
     }
+
+    pub fn intersect_location_xy(&self, other: &Self) -> Option<(f64, f64)> {
+        let (s, _) = self.intersect_times(other)?;
+
+        let x = self.position.x as f64 + s * self.velocity.x as f64;
+        let y = self.position.y as f64 + s * self.velocity.y as f64;
+
+        Some((x, y))
+    }
+}
+
+fn parse_coordinate(input: &str) -> IResult<&str, i64> {
+    // values are padded to line up, so there may be extra leading spaces
+    preceded(space0, complete::i64)(input)
 }
 
 fn parse_point(input: &str) -> IResult<&str, Point3D<i64>> {
     combinator::map(
         tuple((
-            complete::i64,
+            parse_coordinate,
             terminated(complete::char(','), space1),
-            complete::i64,
+            parse_coordinate,
             terminated(complete::char(','), space1),
-            complete::i64,
+            parse_coordinate,
         )),
         |(x, _, y, _, z)| Point3D::new(x, y, z),
     )(input)
@@ -112,9 +134,17 @@ pub struct NeverTellMeTheOddsGen<const A: i64, const B: i64> {
 }
 
 impl<const A: i64, const B: i64> NeverTellMeTheOddsGen<A, B> {
+    pub fn hailstones(&self) -> &[Hail] {
+        &self.hail
+    }
+
     pub fn intersections(&self) -> usize {
-        let lower = A as f64;
-        let upper = B as f64;
+        self.intersections_in(A as f64, B as f64)
+    }
+
+    /// The number of future xy intersections between pairs of hailstones that
+    /// fall within the region bounded by `lower` and `upper` on both axes.
+    pub fn intersections_in(&self, lower: f64, upper: f64) -> usize {
         let mut count = 0;
         self.hail.iter().tuple_combinations().for_each(|(a, b)| {
             if let Some((ix, iy)) = a.intersect_location_xy(b) {
@@ -223,4 +253,48 @@ mod tests {
         let solution = NeverTellMeTheOddsGen::<7, 27>::solve(input).unwrap();
         assert_eq!(solution, Solution::new(2, 47));
     }
+
+    #[test]
+    fn intersections_in() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+        let inst = NeverTellMeTheOdds::instance(input).unwrap();
+        assert_eq!(inst.intersections_in(7.0, 27.0), 2);
+        assert_eq!(inst.intersections(), 0);
+    }
+
+    #[test]
+    fn hailstones() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+        let inst = NeverTellMeTheOdds::instance(input).unwrap();
+        let hail = inst.hailstones();
+        assert_eq!(hail.len(), 5);
+        assert_eq!(*hail[0].position(), Point3D::new(19, 13, 30));
+        assert_eq!(*hail[4].velocity(), Point3D::new(1, -5, -3));
+    }
+
+    #[test]
+    fn intersect_times() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+        let inst = NeverTellMeTheOdds::instance(input).unwrap();
+        let hail = inst.hailstones();
+
+        let (s, t) = hail[0].intersect_times(&hail[1]).unwrap();
+        assert!(s > 0.0);
+        assert!(t > 0.0);
+
+        // hailstone 0 crossed hailstone 4's path in the past
+        assert!(hail[0].intersect_times(&hail[4]).is_none());
+    }
 }
@@ -15,26 +15,32 @@ use rand::{seq::SliceRandom, thread_rng};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 #[derive(Debug, Default, Clone)]
-pub struct RawNode {
-    name: u64,
-    neighbors: Vec<u64>,
+pub struct RawNode<'a> {
+    name: &'a str,
+    neighbors: Vec<&'a str>,
 }
 
-fn parse_raw_node(input: &str) -> IResult<&str, RawNode> {
+fn parse_raw_node(input: &str) -> IResult<&str, RawNode<'_>> {
     combinator::map(
         separated_pair(
-            combinator::map(alpha1, str_to_u64),
+            alpha1,
             tag(": "),
-            separated_list1(complete::char(' '), combinator::map(alpha1, str_to_u64)),
+            separated_list1(complete::char(' '), alpha1),
         ),
         |(name, neighbors)| RawNode { name, neighbors },
     )(input)
 }
 
-fn parse_raw_nodes(input: &str) -> IResult<&str, Vec<RawNode>> {
+fn parse_raw_nodes(input: &str) -> IResult<&str, Vec<RawNode<'_>>> {
     separated_list1(newline, parse_raw_node)(input)
 }
 
+#[derive(Debug, Default, Clone)]
+struct IdNode {
+    name: u64,
+    neighbors: Vec<u64>,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Node {
     index: usize,
@@ -46,10 +52,28 @@ pub struct Snowverload {
     verticies: Vec<u64>,
     edges: Vec<((u64, u64), i64)>,
     neighbors: FxHashMap<u64, FxHashSet<u64>>,
+    names: FxHashMap<u64, String>,
 }
 
 impl Snowverload {
+    /// Every undirected edge, once, using the component names from the input.
+    pub fn edges_named(&self) -> Vec<(String, String)> {
+        self.edges
+            .iter()
+            // edges holds both directions, so only keep one of them
+            .filter(|((l, r), _)| l < r)
+            .map(|((l, r), _)| (self.names[l].clone(), self.names[r].clone()))
+            .collect()
+    }
+
     pub fn cleave(&mut self) -> usize {
+        let (left, right) = self.component_sizes();
+        left * right
+    }
+
+    /// Cut the three edges that split the graph in two, returning the size of
+    /// each of the resulting components.
+    pub fn component_sizes(&mut self) -> (usize, usize) {
         let mut rng = thread_rng();
 
         loop {
@@ -76,7 +100,7 @@ impl Snowverload {
 
                 // pick one of the cuts to bfs from
                 let ((l, r), _) = min_cut[0];
-                return self.bfs(l) * self.bfs(r);
+                return (self.bfs(l), self.bfs(r));
             }
         }
     }
@@ -110,7 +134,19 @@ impl FromStr for Snowverload {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, raw_nodes) = parse_raw_nodes(s).map_err(|e| e.to_owned())?;
+        let (_, named_nodes) = parse_raw_nodes(s).map_err(|e| e.to_owned())?;
+
+        let mut names: FxHashMap<u64, String> = FxHashMap::default();
+        let mut id = |name: &str| {
+            let v = str_to_u64(name);
+            names.entry(v).or_insert_with(|| name.to_string());
+            v
+        };
+        let raw_nodes = named_nodes
+            .iter()
+            .map(|n| (id(n.name), n.neighbors.iter().map(|ne| id(ne)).collect()))
+            .map(|(name, neighbors)| IdNode { name, neighbors })
+            .collect::<Vec<_>>();
 
         let mut neighbors: FxHashMap<u64, FxHashSet<u64>> = FxHashMap::default();
         let mut verticies_raw = raw_nodes
@@ -150,6 +186,7 @@ impl FromStr for Snowverload {
             verticies,
             edges,
             neighbors,
+            names,
         })
     }
 }
@@ -158,6 +195,7 @@ impl Problem for Snowverload {
     const DAY: usize = 25;
     const TITLE: &'static str = "snowverload";
     const README: &'static str = include_str!("../README.md");
+    const HAS_PART_TWO: bool = false;
 
     type ProblemError = anyhow::Error;
     type P1 = usize;
@@ -204,4 +242,54 @@ frs: qnr lhk lsr";
         let solution = Snowverload::solve(input).unwrap();
         assert_eq!(solution, Solution::new(54, "no part 2 for day 25"));
     }
+
+    #[test]
+    fn edges_named() {
+        let input = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+        let inst = Snowverload::instance(input).unwrap();
+        let edges = inst.edges_named();
+        assert_eq!(edges.len(), 33);
+        assert!(edges
+            .iter()
+            .any(|(l, r)| (l == "jqt" && r == "rhn") || (l == "rhn" && r == "jqt")));
+    }
+
+    #[test]
+    fn component_sizes() {
+        let input = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+        let mut inst = Snowverload::instance(input).unwrap();
+        let (left, right) = inst.component_sizes();
+        assert_eq!(left * right, 54);
+        assert_eq!(left + right, 15);
+    }
+
+    #[test]
+    fn no_part_two() {
+        let has_part_two = Snowverload::HAS_PART_TWO;
+        assert!(!has_part_two);
+    }
 }